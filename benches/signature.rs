@@ -0,0 +1,65 @@
+use std::{hint::black_box, str::FromStr};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::prelude::*;
+use rosu_mem::signature::{find_signature, Signature};
+
+/// Previous implementation, kept for comparison
+fn find_signature_naive(buff: &[u8], sign: &Signature) -> Option<usize> {
+    buff.windows(sign.len())
+        .enumerate()
+        .find_map(|(i, window)| (sign.bytes() == window).then_some(i))
+}
+
+/// Buffer that somewhat looks like a real memory region: lots of zeroes
+/// and random bytes in between, with signature placed at the very end
+fn synthetic_region(size: usize, tail: &[u8]) -> Vec<u8> {
+    let mut rng = StdRng::seed_from_u64(727);
+
+    let mut buff: Vec<u8> = (0..size)
+        .map(|_| if rng.gen_bool(0.5) { 0 } else { rng.gen() })
+        .collect();
+
+    let len = buff.len();
+    buff[len - tail.len()..].copy_from_slice(tail);
+
+    buff
+}
+
+fn bench_find_signature(c: &mut Criterion) {
+    const SIZE: usize = 64 * 1024 * 1024;
+
+    let cases = [
+        (
+            "stable",
+            "F8 01 74 04 83 65",
+            [0xF8, 0x01, 0x74, 0x04, 0x83, 0x65],
+        ),
+        (
+            "wildcards",
+            "48 ?? F8 ?? 73 ??",
+            [0x48, 0x83, 0xF8, 0x04, 0x73, 0x1E],
+        ),
+    ];
+
+    for (name, pattern, tail) in cases {
+        let sign = Signature::from_str(pattern).unwrap();
+        let buff = synthetic_region(SIZE, &tail);
+
+        let mut group = c.benchmark_group(name);
+        group.sample_size(10);
+
+        group.bench_function("anchored", |b| {
+            b.iter(|| find_signature(black_box(&buff), black_box(&sign)))
+        });
+
+        group.bench_function("naive", |b| {
+            b.iter(|| find_signature_naive(black_box(&buff), black_box(&sign)))
+        });
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_find_signature);
+criterion_main!(benches);
@@ -1,9 +1,9 @@
 use rosu_mem::{
     error::ProcessError,
+    pointer::{PointerChain, PointerWidth},
     process::{Process, ProcessTraits},
-    signature::Signature,
+    signature,
 };
-use std::str::FromStr;
 
 fn main() -> Result<(), ProcessError> {
     // Initialize a process first
@@ -12,24 +12,24 @@ fn main() -> Result<(), ProcessError> {
     println!("Found a osu! lazer process");
 
     // Initialize a signatures
-    let scaling_container_target_draw_size = Signature::from_str(
-        "00 00 80 44 00 00 40 44 00 00 00 00 ?? ?? ?? ?? 00 00 00 00",
-    )
-    .unwrap();
+    let scaling_container_target_draw_size = signature!(
+        "00 00 80 44 00 00 40 44 00 00 00 00 ?? ?? ?? ?? 00 00 00 00"
+    );
 
-    // Scan process for pre-initialized signatures
-    // Be aware that osu! lazer uses usize for addresses, so we also
+    // Describe how to get from signature to the game base
+    // Be aware that osu! lazer uses 8 byte pointers, so we also
     // explicitly defining that
     // Also reading a values for lazer is bit more tidious than stable :)
-    let scaling_container: usize =
-        osu_process.read_signature(&scaling_container_target_draw_size)?;
-
-    let external_link_opener =
-        osu_process.read_i64(scaling_container - 0x24)?;
-
-    let api = osu_process.read_i64(external_link_opener + 0x218)?;
+    let game_base_chain = PointerChain::new(
+        scaling_container_target_draw_size,
+        PointerWidth::X64,
+    )
+    .offset(-0x24) // external link opener
+    .offset(0x218) // api
+    .offset(0x1f8); // game base
 
-    let game_base = osu_process.read_i64(api + 0x1f8)?;
+    // Scan process for signature and follow the chain
+    let game_base = osu_process.resolve_chain(&game_base_chain)?;
 
     println!("Read a game base!");
 
@@ -0,0 +1,238 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, Field,
+    Fields, GenericArgument, LitInt, PathArguments, Type,
+};
+
+const PRIMITIVES: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128",
+    "f32", "f64",
+];
+
+/// Generates `rosu_mem::remote::RemoteStruct` implementation
+///
+/// Struct attributes:
+/// * `#[pointer_width(32)]` / `#[pointer_width(64)]` - size of pointers
+///   and layouts of strings and lists inside of target process,
+///   overrides `ProcessTraits::arch()` which is used by default
+///
+/// Field attributes:
+/// * `#[offset(0x2C)]` - offset of the field from the struct address,
+///   required for every field
+/// * `#[ptr]` - field contains a pointer, value is read from the
+///   address it points to
+/// * `#[csharp_string]` - field contains a pointer to C# string
+///
+/// Field types:
+/// * Primitives - read with `read_*` functions
+/// * `Vec<primitive>` - CLR `List<T>`, read with `read_list_with_width`
+/// * `String` - requires `#[csharp_string]`
+/// * Anything else - read with `RemoteStruct::read_from`
+#[proc_macro_derive(
+    RemoteStruct,
+    attributes(pointer_width, offset, ptr, csharp_string)
+)]
+pub fn derive_remote_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.span(),
+            "RemoteStruct can only be derived for structs",
+        ));
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            input.span(),
+            "RemoteStruct requires named fields",
+        ));
+    };
+
+    let width = pointer_width(&input)?;
+
+    let mut reads = Vec::with_capacity(fields.named.len());
+    let mut names = Vec::with_capacity(fields.named.len());
+
+    for field in &fields.named {
+        let name = field.ident.as_ref().unwrap();
+
+        reads.push(field_read(field, &width)?);
+        names.push(name);
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::rosu_mem::remote::RemoteStruct
+            for #ident #ty_generics #where_clause
+        {
+            fn read_from<P: ::rosu_mem::process::ProcessTraits>(
+                process: &P,
+                addr: usize,
+            ) -> ::core::result::Result<Self, ::rosu_mem::error::ProcessError>
+            {
+                ::core::result::Result::Ok(Self { #(#names: #reads),* })
+            }
+        }
+    })
+}
+
+struct Width {
+    /// Expression evaluating to `rosu_mem::pointer::PointerWidth`
+    variant: TokenStream2,
+}
+
+fn pointer_width(input: &DeriveInput) -> Result<Width, Error> {
+    let mut bits = None;
+
+    for attr in &input.attrs {
+        if attr.path().is_ident("pointer_width") {
+            bits = Some(attr.parse_args::<LitInt>()?.base10_parse::<u32>()?);
+        }
+    }
+
+    match bits {
+        None => Ok(Width {
+            variant: quote! {
+                ::rosu_mem::process::ProcessTraits::arch(process).width()
+            },
+        }),
+        Some(32) => Ok(Width {
+            variant: quote!(::rosu_mem::pointer::PointerWidth::X32),
+        }),
+        Some(64) => Ok(Width {
+            variant: quote!(::rosu_mem::pointer::PointerWidth::X64),
+        }),
+        _ => Err(Error::new(
+            input.span(),
+            "pointer_width can be either 32 or 64",
+        )),
+    }
+}
+
+fn field_read(field: &Field, width: &Width) -> Result<TokenStream2, Error> {
+    let mut offset: Option<Expr> = None;
+    let mut is_ptr = false;
+    let mut is_string = false;
+
+    for attr in &field.attrs {
+        if attr.path().is_ident("offset") {
+            offset = Some(attr.parse_args()?);
+        } else if attr.path().is_ident("ptr") {
+            is_ptr = true;
+        } else if attr.path().is_ident("csharp_string") {
+            is_string = true;
+        }
+    }
+
+    let Some(offset) = offset else {
+        return Err(Error::new(
+            field.span(),
+            "missing `#[offset(..)]` attribute",
+        ));
+    };
+
+    let field_addr = quote!(addr.wrapping_add((#offset) as usize));
+
+    let variant = &width.variant;
+
+    if is_string {
+        return Ok(quote! {
+            process.read_string_with_width(
+                process.read_ptr(#field_addr, #variant)?,
+                #variant,
+            )?
+        });
+    }
+
+    let value_addr = if is_ptr {
+        quote!(process.read_ptr(#field_addr, #variant)?)
+    } else {
+        field_addr
+    };
+
+    if let Some(prim) = primitive(&field.ty) {
+        let read = format_ident!("read_{}", prim);
+
+        return Ok(quote!(process.#read(#value_addr)?));
+    }
+
+    if let Some(prim) = vec_of_primitive(&field.ty) {
+        let prim = format_ident!("{}", prim);
+
+        return Ok(quote! {{
+            let mut buff: ::std::vec::Vec<#prim> = ::std::vec::Vec::new();
+            process.read_list_with_width(#value_addr, #variant, &mut buff)?;
+            buff
+        }});
+    }
+
+    if type_segment(&field.ty).is_some_and(|segment| segment.ident == "String")
+    {
+        return Err(Error::new(
+            field.span(),
+            "String fields require `#[csharp_string]` attribute",
+        ));
+    }
+
+    let ty = &field.ty;
+
+    Ok(quote! {
+        <#ty as ::rosu_mem::remote::RemoteStruct>::read_from(
+            process,
+            #value_addr,
+        )?
+    })
+}
+
+fn type_segment(ty: &Type) -> Option<&syn::PathSegment> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+
+    if path.qself.is_some() {
+        return None;
+    }
+
+    path.path.segments.last()
+}
+
+fn primitive(ty: &Type) -> Option<String> {
+    let segment = type_segment(ty)?;
+
+    if !segment.arguments.is_none() {
+        return None;
+    }
+
+    let name = segment.ident.to_string();
+
+    PRIMITIVES.contains(&name.as_str()).then_some(name)
+}
+
+fn vec_of_primitive(ty: &Type) -> Option<String> {
+    let segment = type_segment(ty)?;
+
+    if segment.ident != "Vec" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    match args.args.first()? {
+        GenericArgument::Type(inner) => primitive(inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,255 @@
+use std::io::Read;
+
+use rosu_mem::{
+    arch::TargetArch,
+    error::ProcessError,
+    process::{MemoryRegion, ProcessTraits},
+    remote::RemoteStruct,
+};
+
+pub struct FakeProccess {
+    buff: Vec<u8>,
+    arch: TargetArch,
+}
+
+impl ProcessTraits for FakeProccess {
+    fn initialize(
+        _proc_name: &str,
+        _exclude: &[&str],
+    ) -> Result<Self, ProcessError> {
+        todo!()
+    }
+
+    fn find_process(
+        _proc_name: &str,
+        _exclude: &[&str],
+    ) -> Result<Self, ProcessError> {
+        todo!()
+    }
+
+    fn read_regions(self) -> Result<Self, ProcessError> {
+        todo!()
+    }
+
+    fn regions(&self) -> &[MemoryRegion] {
+        &[]
+    }
+
+    fn arch(&self) -> TargetArch {
+        self.arch
+    }
+
+    fn read<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        len: usize,
+        buff: &mut [u8],
+    ) -> Result<(), ProcessError> {
+        let addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        let Some(mut slice) = self.buff.get(addr..addr + len) else {
+            return Err(ProcessError::BadAddress(addr, len));
+        };
+
+        let _ = slice.read(buff);
+
+        Ok(())
+    }
+
+    fn write<T: TryInto<usize>>(
+        &self,
+        _addr: T,
+        _buff: &[u8],
+    ) -> Result<(), ProcessError> {
+        todo!()
+    }
+}
+
+#[derive(RemoteStruct, Debug, PartialEq)]
+struct Inner {
+    #[offset(0x0)]
+    value: u16,
+}
+
+#[derive(RemoteStruct, Debug, PartialEq)]
+struct Stable {
+    #[offset(0x4)]
+    ar: f32,
+    #[offset(0x8)]
+    #[ptr]
+    combo: i32,
+    #[offset(0xC)]
+    #[csharp_string]
+    artist: String,
+    #[offset(0x10)]
+    #[ptr]
+    times: Vec<i32>,
+    #[offset(0x8)]
+    #[ptr]
+    inner: Inner,
+}
+
+/// Generated code doesn't depend on `Result`, `Ok` and `Vec` in scope
+#[allow(dead_code, non_snake_case)]
+mod shadowed {
+    use rosu_mem::remote::RemoteStruct;
+
+    type Result<T> = std::result::Result<T, ()>;
+    struct Vec;
+    fn Ok() {}
+
+    #[derive(RemoteStruct)]
+    struct Shadowed {
+        #[offset(0x0)]
+        #[ptr]
+        times: std::vec::Vec<i32>,
+    }
+}
+
+#[derive(RemoteStruct, Debug, PartialEq)]
+struct Lazer {
+    #[offset(0x0)]
+    #[ptr]
+    time: f64,
+    #[offset(0x8)]
+    #[csharp_string]
+    path: String,
+}
+
+fn put(buff: &mut Vec<u8>, at: usize, bytes: &[u8]) {
+    if buff.len() < at + bytes.len() {
+        buff.resize(at + bytes.len(), 0);
+    }
+
+    buff[at..at + bytes.len()].copy_from_slice(bytes);
+}
+
+#[test]
+fn test_derive_stable() {
+    let mut buff = vec![0u8; 0x100];
+
+    // Struct itself
+    put(&mut buff, 0x4, &9.5f32.to_le_bytes());
+    put(&mut buff, 0x8, &0x40u32.to_le_bytes());
+    put(&mut buff, 0xC, &0x50u32.to_le_bytes());
+    put(&mut buff, 0x10, &0x70u32.to_le_bytes());
+
+    // Combo
+    put(&mut buff, 0x40, &727i32.to_le_bytes());
+
+    // String: 4B header, 4B length, UTF-16 string
+    put(&mut buff, 0x54, &2u32.to_le_bytes());
+    put(&mut buff, 0x58, &[b'o', 0, b'k', 0]);
+
+    // Array: 4B header, items ptr, 4B, size
+    put(&mut buff, 0x74, &0x90u32.to_le_bytes());
+    put(&mut buff, 0x7C, &2i32.to_le_bytes());
+
+    // Backing array: 4B header, 4B length, items
+    put(&mut buff, 0x94, &2u32.to_le_bytes());
+    put(&mut buff, 0x98, &100i32.to_le_bytes());
+    put(&mut buff, 0x9C, &200i32.to_le_bytes());
+
+    let p = FakeProccess {
+        buff,
+        arch: TargetArch::X86,
+    };
+
+    let value = Stable::read_from(&p, 0).unwrap();
+
+    assert_eq!(
+        value,
+        Stable {
+            ar: 9.5,
+            combo: 727,
+            artist: "ok".to_string(),
+            times: vec![100, 200],
+            inner: Inner { value: 727 },
+        }
+    );
+}
+
+#[test]
+fn test_derive_lazer() {
+    let mut buff = vec![0u8; 0x100];
+
+    put(&mut buff, 0x10, &0x40u64.to_le_bytes());
+    put(&mut buff, 0x18, &0x60u64.to_le_bytes());
+
+    put(&mut buff, 0x40, &1337.5f64.to_le_bytes());
+
+    // String: 8B header, 4B length, UTF-16 string
+    put(&mut buff, 0x68, &1u32.to_le_bytes());
+    put(&mut buff, 0x6C, &[b'a', 0]);
+
+    let p = FakeProccess {
+        buff,
+        arch: TargetArch::X64,
+    };
+
+    let value = Lazer::read_from(&p, 0x10).unwrap();
+
+    assert_eq!(
+        value,
+        Lazer {
+            time: 1337.5,
+            path: "a".to_string(),
+        }
+    );
+
+    assert!(Lazer::read_from(&p, 0xFC).is_err());
+}
+
+#[derive(RemoteStruct, Debug, PartialEq)]
+#[pointer_width(32)]
+struct Narrow {
+    #[offset(0x0)]
+    #[ptr]
+    value: u16,
+    #[offset(0x8)]
+    #[csharp_string]
+    name: String,
+    #[offset(0xC)]
+    #[ptr]
+    times: Vec<i32>,
+}
+
+#[test]
+fn test_derive_pointer_width_override() {
+    let mut buff = vec![0u8; 0x100];
+
+    // Upper half of a 64 bit pointer is not read
+    put(&mut buff, 0x0, &0x40u32.to_le_bytes());
+    put(&mut buff, 0x4, &0xFFu32.to_le_bytes());
+    put(&mut buff, 0x8, &0x50u32.to_le_bytes());
+    put(&mut buff, 0xC, &0x70u32.to_le_bytes());
+
+    put(&mut buff, 0x40, &42u16.to_le_bytes());
+
+    // Strings and lists use 32 bit layouts too
+    put(&mut buff, 0x54, &2u32.to_le_bytes());
+    put(&mut buff, 0x58, &[b'o', 0, b'k', 0]);
+
+    put(&mut buff, 0x74, &0x90u32.to_le_bytes());
+    put(&mut buff, 0x7C, &2i32.to_le_bytes());
+
+    put(&mut buff, 0x94, &2u32.to_le_bytes());
+    put(&mut buff, 0x98, &100i32.to_le_bytes());
+    put(&mut buff, 0x9C, &200i32.to_le_bytes());
+
+    let p = FakeProccess {
+        buff,
+        arch: TargetArch::X64,
+    };
+
+    assert_eq!(
+        Narrow::read_from(&p, 0).unwrap(),
+        Narrow {
+            value: 42,
+            name: "ok".to_string(),
+            times: vec![100, 200],
+        }
+    );
+}
@@ -0,0 +1,95 @@
+//! Architecture of the target process
+//!
+//! Decides sizes of pointers and layouts of CLR objects, which are
+//! different between osu! stable (32 bit, also when running under
+//! wine) and osu! lazer (64 bit)
+use crate::{
+    elf::{ElfImage, ELF_MAGIC},
+    pe::{PeImage, DOS_MAGIC},
+    pointer::PointerWidth,
+    process::ProcessTraits,
+};
+
+/// Architecture of the target process
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TargetArch {
+    /// 32 bit process (osu! stable)
+    X86,
+    /// 64 bit process (osu! lazer)
+    X64,
+}
+
+impl TargetArch {
+    /// Architecture of the current process
+    pub const HOST: Self = if cfg!(target_pointer_width = "64") {
+        TargetArch::X64
+    } else {
+        TargetArch::X86
+    };
+
+    /// Width of pointers inside of the process
+    #[inline]
+    pub const fn width(self) -> PointerWidth {
+        match self {
+            TargetArch::X86 => PointerWidth::X32,
+            TargetArch::X64 => PointerWidth::X64,
+        }
+    }
+
+    /// Size of a pointer in bytes
+    #[inline]
+    pub const fn ptr_size(self) -> usize {
+        self.width().size()
+    }
+
+    /// Offset of the first character of a CLR string, after method
+    /// table pointer and 4 byte length
+    #[inline]
+    pub const fn string_data_offset(self) -> usize {
+        self.ptr_size() + 4
+    }
+
+    /// Offset of the first element of a CLR array, after method table
+    /// pointer and length, which is padded to pointer size
+    #[inline]
+    pub const fn array_data_offset(self) -> usize {
+        self.ptr_size() * 2
+    }
+
+    /// Detects architecture from headers of the `module` image mapped
+    /// into the process, both PE (native windows or wine) and ELF
+    /// images are supported.
+    ///
+    /// Returns `None` if module isn't mapped or isn't a valid image
+    pub fn detect<P: ProcessTraits>(process: &P, module: &str) -> Option<Self> {
+        let base = process.module(module)?.base;
+
+        let mut magic = [0u8; 4];
+        process.read(base, magic.len(), &mut magic).ok()?;
+
+        let width = if magic.starts_with(DOS_MAGIC) {
+            PeImage::read(process, base).ok()?.width
+        } else if &magic == ELF_MAGIC {
+            ElfImage::read(process, base).ok()?.width
+        } else {
+            return None;
+        };
+
+        Some(width.into())
+    }
+}
+
+impl From<PointerWidth> for TargetArch {
+    fn from(value: PointerWidth) -> Self {
+        match value {
+            PointerWidth::X32 => TargetArch::X86,
+            PointerWidth::X64 => TargetArch::X64,
+        }
+    }
+}
+
+impl From<TargetArch> for PointerWidth {
+    fn from(value: TargetArch) -> Self {
+        value.width()
+    }
+}
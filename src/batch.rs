@@ -0,0 +1,68 @@
+/// Single queued read of a [`ReadBatch`]
+#[derive(Debug)]
+pub struct BatchEntry<'a> {
+    pub addr: usize,
+    pub buff: &'a mut [u8],
+}
+
+/// Queue of reads that are executed together by
+/// [`crate::process::ProcessTraits::read_batch()`]
+///
+/// On linux whole batch is executed using as few `process_vm_readv`
+/// calls as possible, which is much faster than reading values one by one.
+///
+/// ```
+/// use rosu_mem::batch::ReadBatch;
+///
+/// let mut ar = [0u8; 4];
+/// let mut combo = [0u8; 2];
+///
+/// let mut batch = ReadBatch::new();
+/// assert_eq!(batch.push(0x1000, &mut ar), 0);
+/// assert_eq!(batch.push(0x2000, &mut combo), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct ReadBatch<'a> {
+    entries: Vec<BatchEntry<'a>>,
+}
+
+impl<'a> ReadBatch<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Queues a read of `buff.len()` bytes at `addr` into `buff`.
+    ///
+    /// Returns index of the entry, same index is used
+    /// for the entry result after executing the batch
+    pub fn push(&mut self, addr: usize, buff: &'a mut [u8]) -> usize {
+        self.entries.push(BatchEntry { addr, buff });
+        self.entries.len() - 1
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all queued reads
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    #[inline]
+    pub fn entries_mut(&mut self) -> &mut [BatchEntry<'a>] {
+        &mut self.entries
+    }
+}
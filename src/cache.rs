@@ -0,0 +1,310 @@
+//! Persistent cache of [`SignatureSet`] matches
+//!
+//! Matches are stored relative to the base of executable image and
+//! keyed by size, modification time and hash of the executable, so
+//! any update of the game invalidates the whole cache. Every cached
+//! match is also verified by comparing signature with memory at the
+//! cached address before it is used.
+//!
+//! Notes:
+//! * Only matches inside of memory that doesn't move between launches
+//!   benefit from caching. Code that is generated at runtime (like JIT
+//!   code of osu! stable) lives in anonymous memory, such matches
+//!   fail verification and are scanned again
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    database::{SignatureEntry, SignatureSet},
+    error::DatabaseError,
+    process::{MemoryRegion, ProcessTraits},
+    region::RegionFilter,
+    signature::Signature,
+};
+
+/// Identifies exact build of the executable
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CacheKey {
+    size: u64,
+    modified: u64,
+    /// FNV-1a of the whole file, as a hex string since TOML integers
+    /// are signed
+    hash: String,
+}
+
+impl CacheKey {
+    fn new(executable: &Path) -> Result<Self, DatabaseError> {
+        let metadata = std::fs::metadata(executable)?;
+
+        let modified = metadata
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs());
+
+        let mut file = File::open(executable)?;
+        let mut buff = vec![0u8; 64 * 1024];
+        let mut hash: u64 = 0xCBF2_9CE4_8422_2325;
+
+        loop {
+            let read = file.read(&mut buff)?;
+
+            if read == 0 {
+                break;
+            }
+
+            for byte in &buff[..read] {
+                hash ^= *byte as u64;
+                hash = hash.wrapping_mul(0x0100_0000_01B3);
+            }
+        }
+
+        Ok(Self {
+            size: metadata.len(),
+            modified,
+            hash: format!("{hash:016x}"),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedMatch {
+    name: String,
+    pattern: String,
+    /// Offset from the executable image base
+    offset: i64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    key: CacheKey,
+    #[serde(default, rename = "match")]
+    matches: Vec<CachedMatch>,
+}
+
+/// Signature matches of a single executable build stored in a file
+///
+/// ```no_run
+/// use rosu_mem::{
+///     cache::SignatureCache,
+///     database::SignatureSet,
+///     process::{Process, ProcessTraits},
+/// };
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let process = Process::initialize("osu!.exe", &[])?;
+/// let set = SignatureSet::load("signatures/stable.toml")?;
+///
+/// // Path of the mapped image exists on the host, unlike
+/// // `executable_path` which is taken from the command line under
+/// // wine and is only correct for the `Z:` drive
+/// let executable = process.module("osu!.exe").unwrap().path;
+/// let mut cache = SignatureCache::open("signatures.cache", executable)?;
+///
+/// let addresses = set.resolve_cached(&process, &mut cache)?;
+/// cache.save()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SignatureCache {
+    path: PathBuf,
+    executable: PathBuf,
+    key: CacheKey,
+    /// `(name, pattern)` to offset from the image base
+    matches: HashMap<(String, String), i64>,
+}
+
+impl SignatureCache {
+    /// Opens cache stored at `path` for the `executable`, which has to
+    /// be a path on the host, like
+    /// [`Module::path`](crate::module::Module::path).
+    ///
+    /// Cache is empty if the file doesn't exist, can't be parsed or
+    /// was made for a different build of the executable
+    pub fn open(
+        path: impl Into<PathBuf>,
+        executable: impl Into<PathBuf>,
+    ) -> Result<Self, DatabaseError> {
+        let path = path.into();
+        let executable = executable.into();
+        let key = CacheKey::new(&executable)?;
+
+        let file: CacheFile = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|value| toml::from_str(&value).ok())
+            .unwrap_or_default();
+
+        let matches = if file.key == key {
+            file.matches
+                .into_iter()
+                .map(|m| ((m.name, m.pattern), m.offset))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        Ok(Self {
+            path,
+            executable,
+            key,
+            matches,
+        })
+    }
+
+    /// Writes cache back to the file it was opened from
+    pub fn save(&self) -> Result<(), DatabaseError> {
+        let mut matches: Vec<CachedMatch> = self
+            .matches
+            .iter()
+            .map(|((name, pattern), offset)| CachedMatch {
+                name: name.clone(),
+                pattern: pattern.clone(),
+                offset: *offset,
+            })
+            .collect();
+
+        matches.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let file = CacheFile {
+            key: self.key.clone(),
+            matches,
+        };
+
+        std::fs::write(&self.path, toml::to_string(&file)?)?;
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.matches.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.matches.clear();
+    }
+
+    /// Lowest address of regions mapped from the executable
+    fn image_base(&self, regions: &[MemoryRegion]) -> Option<usize> {
+        // Comparing only file names, because on wine executable path
+        // is translated from windows one and may differ
+        let name = self.executable.file_name()?;
+
+        regions
+            .iter()
+            .filter(|region| {
+                region
+                    .path
+                    .as_ref()
+                    .is_some_and(|path| path.file_name() == Some(name))
+            })
+            .map(|region| region.from)
+            .min()
+    }
+
+    fn get(&self, entry: &SignatureEntry, base: usize) -> Option<usize> {
+        let offset = self
+            .matches
+            .get(&(entry.name.clone(), entry.signature.to_string()))?;
+
+        Some(base.wrapping_add_signed(*offset as isize))
+    }
+
+    fn remove(&mut self, entry: &SignatureEntry) {
+        self.matches
+            .remove(&(entry.name.clone(), entry.signature.to_string()));
+    }
+
+    fn insert(&mut self, entry: &SignatureEntry, base: usize, addr: usize) {
+        let offset = addr.wrapping_sub(base) as isize as i64;
+
+        self.matches
+            .insert((entry.name.clone(), entry.signature.to_string()), offset);
+    }
+}
+
+/// Checks that `sign` matches memory at `addr`
+fn matches_at<P: ProcessTraits>(
+    process: &P,
+    sign: &Signature,
+    addr: usize,
+) -> bool {
+    let mut buff = vec![0u8; sign.len()];
+
+    if process.read(addr, buff.len(), &mut buff).is_err() {
+        return false;
+    }
+
+    sign.bytes()
+        .iter()
+        .zip(&buff)
+        .all(|(sign, byte)| sign == byte)
+}
+
+impl SignatureSet {
+    /// Same as [`SignatureSet::resolve()`] but uses matches from
+    /// `cache` when they are still valid and scans memory only for the
+    /// rest. New matches are stored in `cache`, call
+    /// [`SignatureCache::save()`] to persist them.
+    ///
+    /// If the executable image isn't mapped in the process cache is
+    /// not used at all.
+    pub fn resolve_cached<P: ProcessTraits>(
+        &self,
+        process: &P,
+        cache: &mut SignatureCache,
+    ) -> Result<HashMap<String, usize>, DatabaseError> {
+        self.resolve_cached_filtered(process, &RegionFilter::default(), cache)
+    }
+
+    /// Same as [`SignatureSet::resolve_cached()`] but scans only
+    /// regions matching `filter`
+    pub fn resolve_cached_filtered<P: ProcessTraits>(
+        &self,
+        process: &P,
+        filter: &RegionFilter,
+        cache: &mut SignatureCache,
+    ) -> Result<HashMap<String, usize>, DatabaseError> {
+        let base = cache.image_base(process.regions());
+
+        let mut found = vec![None; self.entries.len()];
+
+        if let Some(base) = base {
+            for (entry, found) in self.entries.iter().zip(&mut found) {
+                let Some(addr) = cache.get(entry, base) else {
+                    continue;
+                };
+
+                if matches_at(process, &entry.signature, addr) {
+                    *found = Some(addr);
+                } else {
+                    cache.remove(entry);
+                }
+            }
+        }
+
+        let found = self.scan(process, filter, found)?;
+
+        if let Some(base) = base {
+            for (entry, addr) in self.entries.iter().zip(&found) {
+                if let Some(addr) = addr {
+                    cache.insert(entry, base, *addr);
+                }
+            }
+        }
+
+        self.resolve_matches(process, found)
+    }
+}
@@ -0,0 +1,537 @@
+//! Named signature sets loaded from TOML files
+//!
+//! ```toml
+//! [[signature]]
+//! name = "base"
+//! pattern = "F8 01 74 04 83 65"
+//!
+//! # Pointer stored right before the pattern
+//! [[signature]]
+//! name = "status"
+//! pattern = "[?? ?? ?? ??] 48 83 F8 04 73 1E"
+//! capture = "absolute"
+//! versions = ">=20240101"
+//!
+//! # Entries with the same name are alternatives, first one that
+//! # resolves is used
+//! [[signature]]
+//! name = "rulesets"
+//! pattern = "7D 15 A1 ?? ?? ?? ?? 85 C0"
+//! offset = -11
+//! chain = [0x4, 0x4]
+//! width = 32
+//! ```
+//!
+//! Fields of the entry:
+//! * `name` - name of the resolved address
+//! * `pattern` - signature, see [`Signature::from_str()`]
+//! * `capture` - `"absolute"` or `"relative"`, decodes `[..]` capture
+//!   of the pattern instead of using match address, see
+//!   [`ProcessTraits::read_capture()`]
+//! * `offset` - added to the address, TOML doesn't allow negative
+//!   hex numbers so negative offsets have to be written in decimal
+//! * `chain` - [`PointerChain`] offsets that are followed afterwards
+//! * `width` - pointer width of the chain, `32` (default) or `64`
+//! * `versions` - comma separated game version constraints like
+//!   `">=2024.1009, <2025.101"`, see [`VersionReq`]
+use std::{collections::HashMap, path::Path, str::FromStr};
+
+use serde::Deserialize;
+
+use crate::{
+    error::{DatabaseError, ProcessError},
+    pointer::{ChainBase, PointerChain, PointerWidth},
+    process::ProcessTraits,
+    region::RegionFilter,
+    signature::{CaptureKind, Signature},
+};
+
+/// Game version, osu! stable `b20240123.2` or osu! lazer `2024.1009.1`.
+///
+/// Only leading numeric parts are compared, so suffixes like `-lazer`
+/// or `cuttingedge` are ignored and `2024.1009` is equal to
+/// `2024.1009.0`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GameVersion(Vec<u64>);
+
+impl FromStr for GameVersion {
+    type Err = DatabaseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+        let trimmed = trimmed.strip_prefix(['b', 'v']).unwrap_or(trimmed);
+
+        let mut parts = Vec::new();
+
+        for part in trimmed.split('.') {
+            let digits = part
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(part.len());
+
+            let Ok(number) = part[..digits].parse() else {
+                break;
+            };
+
+            parts.push(number);
+
+            if digits != part.len() {
+                break;
+            }
+        }
+
+        if parts.is_empty() {
+            return Err(DatabaseError::InvalidVersion(value.to_owned()));
+        }
+
+        while parts.last() == Some(&0) {
+            parts.pop();
+        }
+
+        Ok(Self(parts))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Less,
+    LessEq,
+    Eq,
+    GreaterEq,
+    Greater,
+}
+
+/// Set of game version constraints like `">=20240101, <20250101"`,
+/// every constraint must be satisfied. Empty set matches any version.
+///
+/// Supported operators are `<`, `<=`, `=`, `>=` and `>`, version
+/// without an operator is the same as `=`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VersionReq(Vec<(Comparison, GameVersion)>);
+
+impl VersionReq {
+    pub fn matches(&self, version: &GameVersion) -> bool {
+        self.0.iter().all(|(cmp, req)| match cmp {
+            Comparison::Less => version < req,
+            Comparison::LessEq => version <= req,
+            Comparison::Eq => version == req,
+            Comparison::GreaterEq => version >= req,
+            Comparison::Greater => version > req,
+        })
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = DatabaseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut constraints = Vec::new();
+
+        for constraint in value.split(',').map(str::trim) {
+            if constraint.is_empty() {
+                continue;
+            }
+
+            let (cmp, version) = [
+                ("<=", Comparison::LessEq),
+                (">=", Comparison::GreaterEq),
+                ("<", Comparison::Less),
+                (">", Comparison::Greater),
+                ("=", Comparison::Eq),
+            ]
+            .into_iter()
+            .find_map(|(op, cmp)| {
+                constraint.strip_prefix(op).map(|rest| (cmp, rest))
+            })
+            .unwrap_or((Comparison::Eq, constraint));
+
+            constraints.push((cmp, version.parse()?));
+        }
+
+        Ok(Self(constraints))
+    }
+}
+
+/// Single entry of a [`SignatureSet`], see [module](self) docs for
+/// the meaning of every field
+#[derive(Debug)]
+pub struct SignatureEntry {
+    pub name: String,
+    pub signature: Signature,
+    pub capture: Option<CaptureKind>,
+    pub offset: isize,
+    pub chain: Vec<isize>,
+    pub width: PointerWidth,
+    pub versions: VersionReq,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawEntry {
+    name: String,
+    pattern: String,
+    #[serde(default)]
+    capture: Option<CaptureKind>,
+    #[serde(default)]
+    offset: isize,
+    #[serde(default)]
+    chain: Vec<isize>,
+    #[serde(default)]
+    width: Option<u32>,
+    #[serde(default)]
+    versions: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawSet {
+    #[serde(default, rename = "signature")]
+    signatures: Vec<RawEntry>,
+}
+
+impl TryFrom<RawEntry> for SignatureEntry {
+    type Error = DatabaseError;
+
+    fn try_from(raw: RawEntry) -> Result<Self, Self::Error> {
+        let signature = raw.pattern.parse().map_err(|source| {
+            DatabaseError::InvalidPattern {
+                name: raw.name.clone(),
+                source,
+            }
+        })?;
+
+        let width = match raw.width {
+            None | Some(32) => PointerWidth::X32,
+            Some(64) => PointerWidth::X64,
+            Some(width) => {
+                return Err(DatabaseError::InvalidWidth {
+                    name: raw.name,
+                    width,
+                })
+            }
+        };
+
+        let versions = match raw.versions {
+            Some(versions) => versions.parse()?,
+            None => VersionReq::default(),
+        };
+
+        Ok(Self {
+            name: raw.name,
+            signature,
+            capture: raw.capture,
+            offset: raw.offset,
+            chain: raw.chain,
+            width,
+            versions,
+        })
+    }
+}
+
+/// Named signatures that are resolved into addresses all at once
+///
+/// ```no_run
+/// use rosu_mem::{
+///     database::SignatureSet,
+///     process::{Process, ProcessTraits},
+/// };
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let process = Process::initialize("osu!.exe", &[])?;
+///
+/// let mut set = SignatureSet::load("signatures/stable.toml")?;
+/// set.retain_version(&"b20240123.2".parse()?);
+///
+/// let addresses = set.resolve(&process)?;
+/// let status = process.read_u32(addresses["status"])?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct SignatureSet {
+    pub entries: Vec<SignatureEntry>,
+}
+
+impl SignatureSet {
+    pub fn from_toml(value: &str) -> Result<Self, DatabaseError> {
+        let raw: RawSet = toml::from_str(value)?;
+
+        let entries = raw
+            .signatures
+            .into_iter()
+            .map(SignatureEntry::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { entries })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, DatabaseError> {
+        Self::from_toml(&std::fs::read_to_string(path)?)
+    }
+
+    /// Removes entries which version constraints don't match `version`
+    pub fn retain_version(&mut self, version: &GameVersion) {
+        self.entries.retain(|entry| entry.versions.matches(version));
+    }
+
+    /// Resolves every entry into an address, scanning memory only once
+    /// for all signatures.
+    ///
+    /// Entries with the same name are alternatives, the first one that
+    /// resolves is used. If some names can't be resolved at all, every
+    /// one of them is reported in
+    /// [`DatabaseError::UnresolvedSignatures`] together with the error
+    /// of its last alternative.
+    ///
+    /// Notes:
+    /// * Scans same regions as [`ProcessTraits::read_signature()`]
+    pub fn resolve<P: ProcessTraits>(
+        &self,
+        process: &P,
+    ) -> Result<HashMap<String, usize>, DatabaseError> {
+        self.resolve_filtered(process, &RegionFilter::default())
+    }
+
+    /// Same as [`SignatureSet::resolve()`] but scans only regions
+    /// matching `filter`
+    pub fn resolve_filtered<P: ProcessTraits>(
+        &self,
+        process: &P,
+        filter: &RegionFilter,
+    ) -> Result<HashMap<String, usize>, DatabaseError> {
+        let found =
+            self.scan(process, filter, vec![None; self.entries.len()])?;
+
+        self.resolve_matches(process, found)
+    }
+
+    /// Scans memory for entries that have no match in `found` yet,
+    /// skipping names already matched by other alternatives
+    pub(crate) fn scan<P: ProcessTraits>(
+        &self,
+        process: &P,
+        filter: &RegionFilter,
+        mut found: Vec<Option<usize>>,
+    ) -> Result<Vec<Option<usize>>, ProcessError> {
+        let pending: Vec<usize> =
+            (0..self.entries.len())
+                .filter(|&i| {
+                    let name = &self.entries[i].name;
+
+                    !self.entries.iter().zip(&found).any(|(entry, addr)| {
+                        addr.is_some() && &entry.name == name
+                    })
+                })
+                .collect();
+
+        if pending.is_empty() {
+            return Ok(found);
+        }
+
+        let signs: Vec<&Signature> = pending
+            .iter()
+            .map(|&i| &self.entries[i].signature)
+            .collect();
+
+        let scanned: Vec<Option<usize>> =
+            process.scan_signatures_filtered(&signs, filter)?;
+
+        for (i, addr) in pending.into_iter().zip(scanned) {
+            found[i] = addr;
+        }
+
+        Ok(found)
+    }
+
+    /// Turns signature matches of every entry into the final addresses
+    pub(crate) fn resolve_matches<P: ProcessTraits>(
+        &self,
+        process: &P,
+        found: Vec<Option<usize>>,
+    ) -> Result<HashMap<String, usize>, DatabaseError> {
+        let mut resolved = HashMap::with_capacity(self.entries.len());
+        let mut failed: Vec<UnresolvedSignature> = Vec::new();
+
+        for (entry, addr) in self.entries.iter().zip(found) {
+            if resolved.contains_key(&entry.name) {
+                continue;
+            }
+
+            let res = match addr {
+                Some(addr) => entry.resolve_match(process, addr),
+                None => Err(ProcessError::SignatureNotFound(
+                    entry.signature.to_string(),
+                )),
+            };
+
+            match res {
+                Ok(addr) => {
+                    failed.retain(|failed| failed.name != entry.name);
+                    resolved.insert(entry.name.clone(), addr);
+                }
+                Err(error) => {
+                    match failed.iter_mut().find(|f| f.name == entry.name) {
+                        Some(failed) => failed.error = error,
+                        None => failed.push(UnresolvedSignature {
+                            name: entry.name.clone(),
+                            error,
+                        }),
+                    }
+                }
+            }
+        }
+
+        if !failed.is_empty() {
+            return Err(DatabaseError::UnresolvedSignatures(failed));
+        }
+
+        Ok(resolved)
+    }
+}
+
+impl SignatureEntry {
+    /// Turns address of the signature match into the final address
+    fn resolve_match<P: ProcessTraits>(
+        &self,
+        process: &P,
+        addr: usize,
+    ) -> Result<usize, ProcessError> {
+        let addr = match self.capture {
+            Some(kind) => process.read_capture(addr, &self.signature, kind)?,
+            None => addr,
+        };
+
+        let addr = addr.wrapping_add_signed(self.offset);
+
+        if self.chain.is_empty() {
+            return Ok(addr);
+        }
+
+        process.resolve_chain(&PointerChain {
+            base: ChainBase::Address(addr),
+            offsets: self.chain.clone(),
+            width: self.width,
+        })
+    }
+}
+
+/// Name of a [`SignatureEntry`] that couldn't be resolved
+#[derive(Debug)]
+pub struct UnresolvedSignature {
+    pub name: String,
+    pub error: ProcessError,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GameVersion, SignatureSet, VersionReq};
+    use crate::{error::DatabaseError, pointer::PointerWidth};
+
+    fn version(value: &str) -> GameVersion {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_game_version() {
+        assert_eq!(version("b20240123.2"), version("20240123.2"));
+        assert_eq!(version("2024.1009"), version("2024.1009.0"));
+        assert_eq!(version("2024.1009.1-lazer"), version("2024.1009.1"));
+        assert_eq!(version("20240123cuttingedge"), version("20240123"));
+
+        assert!(version("2024.1009.1") > version("2024.1009"));
+        assert!(version("2024.1010") > version("2024.1009.5"));
+        assert!(version("b20240123.2") < version("b20240124"));
+
+        assert!("lazer".parse::<GameVersion>().is_err());
+        assert!("".parse::<GameVersion>().is_err());
+    }
+
+    #[test]
+    fn test_version_req() {
+        let req: VersionReq = ">=2024.1009, <2025.101".parse().unwrap();
+        assert!(req.matches(&version("2024.1009")));
+        assert!(req.matches(&version("2024.1231.3")));
+        assert!(!req.matches(&version("2025.101")));
+        assert!(!req.matches(&version("2024.1008.9")));
+
+        let req: VersionReq = "20240123".parse().unwrap();
+        assert!(req.matches(&version("b20240123")));
+        assert!(!req.matches(&version("b20240123.1")));
+
+        let req: VersionReq = "".parse().unwrap();
+        assert!(req.matches(&version("1")));
+
+        assert!(">=abc".parse::<VersionReq>().is_err());
+    }
+
+    #[test]
+    fn test_from_toml() {
+        let set = SignatureSet::from_toml(
+            r#"
+            [[signature]]
+            name = "base"
+            pattern = "F8 01 74 04 83 65"
+
+            [[signature]]
+            name = "status"
+            pattern = "[?? ?? ?? ??] 48 83 F8 04 73 1E"
+            capture = "absolute"
+            offset = -12
+            chain = [0x4, 0x8]
+            width = 64
+            versions = ">=20240101"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(set.entries.len(), 2);
+        assert_eq!(set.entries[0].name, "base");
+        assert_eq!(set.entries[0].width, PointerWidth::X32);
+        assert_eq!(set.entries[1].offset, -12);
+        assert_eq!(set.entries[1].chain, [4, 8]);
+        assert_eq!(set.entries[1].width, PointerWidth::X64);
+
+        let res = SignatureSet::from_toml(
+            "[[signature]]\nname = \"bad\"\npattern = \"F8 0G\"",
+        );
+        assert!(matches!(
+            res,
+            Err(DatabaseError::InvalidPattern { name, .. }) if name == "bad"
+        ));
+
+        let res = SignatureSet::from_toml(
+            "[[signature]]\nname = \"bad\"\npattern = \"F8\"\nwidth = 16",
+        );
+        assert!(matches!(
+            res,
+            Err(DatabaseError::InvalidWidth { width: 16, .. })
+        ));
+
+        let res = SignatureSet::from_toml(
+            "[[signature]]\nname = \"bad\"\npattern = \"F8\"\nunknown = 1",
+        );
+        assert!(matches!(res, Err(DatabaseError::Toml(_))));
+    }
+
+    #[test]
+    fn test_retain_version() {
+        let mut set = SignatureSet::from_toml(
+            r#"
+            [[signature]]
+            name = "status"
+            pattern = "AA"
+            versions = "<20240101"
+
+            [[signature]]
+            name = "status"
+            pattern = "BB"
+            versions = ">=20240101"
+            "#,
+        )
+        .unwrap();
+
+        set.retain_version(&version("b20240123"));
+
+        assert_eq!(set.entries.len(), 1);
+        assert_eq!(set.entries[0].signature.to_string(), "BB");
+    }
+}
@@ -0,0 +1,433 @@
+//! Parsing of ELF images (executables and `.so` libraries) mapped into
+//! process memory, for example `libcoreclr.so` of osu! lazer on linux
+//!
+//! ```no_run
+//! use rosu_mem::{
+//!     elf::ElfImage,
+//!     process::{Process, ProcessTraits},
+//! };
+//!
+//! # fn main() -> Result<(), rosu_mem::error::ProcessError> {
+//! let process = Process::initialize("osu!", &[])?;
+//! let module = process.module("libcoreclr.so").unwrap();
+//!
+//! let image = ElfImage::read(&process, module.base)?;
+//! let addr = image.symbol(&process, "coreclr_initialize")?;
+//! # Ok(())
+//! # }
+//! ```
+use std::ops::Range;
+
+use crate::{
+    error::ProcessError,
+    image::{u16_at, u32_at, u64_at},
+    pointer::PointerWidth,
+    process::ProcessTraits,
+};
+
+pub(crate) const ELF_MAGIC: &[u8; 4] = b"\x7FELF";
+
+const CLASS_32: u8 = 1;
+const CLASS_64: u8 = 2;
+const DATA_LITTLE_ENDIAN: u8 = 1;
+
+pub const SEGMENT_LOAD: u32 = 1;
+pub const SEGMENT_DYNAMIC: u32 = 2;
+
+pub const SEGMENT_EXECUTE: u32 = 0x1;
+pub const SEGMENT_WRITE: u32 = 0x2;
+pub const SEGMENT_READ: u32 = 0x4;
+
+const DT_NULL: u64 = 0;
+const DT_HASH: u64 = 4;
+const DT_STRTAB: u64 = 5;
+const DT_SYMTAB: u64 = 6;
+const DT_STRSZ: u64 = 10;
+const DT_GNU_HASH: u64 = 0x6FFF_FEF5;
+
+/// Most program headers accepted, real images have about a dozen
+const MAX_SEGMENTS: usize = 128;
+/// Most `PT_DYNAMIC` entries scanned before stopping without `DT_NULL`
+const MAX_DYNAMIC: usize = 1024;
+/// Most symbols, hash buckets or bloom words of a symbol table
+const MAX_SYMBOLS: usize = 1 << 20;
+/// Largest accepted string table (`DT_STRSZ`) in bytes
+const MAX_STRINGS: usize = 64 << 20;
+
+const PAGE_SIZE: usize = 0x1000;
+
+/// Program header of an [`ElfImage`], addresses are already relocated
+/// to the actual load address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    /// `PT_*` value, see [`SEGMENT_LOAD`] and [`SEGMENT_DYNAMIC`]
+    pub kind: u32,
+    /// `PF_*` flags, see [`SEGMENT_EXECUTE`], [`SEGMENT_WRITE`] and
+    /// [`SEGMENT_READ`]
+    pub flags: u32,
+    pub addr: usize,
+    pub size: usize,
+}
+
+impl Segment {
+    /// Memory occupied by the segment, can be passed to
+    /// [`ProcessTraits::read_signature_in()`]
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.addr..self.addr + self.size
+    }
+
+    #[inline]
+    pub fn is_executable(&self) -> bool {
+        self.flags & SEGMENT_EXECUTE != 0
+    }
+
+    #[inline]
+    pub fn is_writable(&self) -> bool {
+        self.flags & SEGMENT_WRITE != 0
+    }
+}
+
+/// Defined symbol from the dynamic symbol table of an [`ElfImage`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub name: String,
+    pub addr: usize,
+    pub size: usize,
+    /// `STT_*` value, `1` for objects and `2` for functions
+    pub kind: u8,
+}
+
+/// Addresses from the `PT_DYNAMIC` segment
+#[derive(Debug, Clone, Copy, Default)]
+struct Dynamic {
+    symtab: usize,
+    strtab: usize,
+    strsz: usize,
+    hash: Option<usize>,
+    gnu_hash: Option<usize>,
+}
+
+/// Headers of an ELF image read from process memory
+#[derive(Debug, Clone)]
+pub struct ElfImage {
+    /// Address where image is actually mapped
+    pub base: usize,
+    /// Difference between virtual addresses from headers and actual
+    /// ones, zero for non-PIE executables
+    pub bias: usize,
+    /// `EM_*` value, `0x3E` for x86-64
+    pub machine: u16,
+    /// ELFCLASS32 images use 32 bit pointers and ELFCLASS64 64 bit
+    pub width: PointerWidth,
+    /// Address of the entry point, `None` if image doesn't have one
+    pub entry_point: Option<usize>,
+    pub segments: Vec<Segment>,
+    dynamic: Option<Dynamic>,
+}
+
+impl ElfImage {
+    /// Reads ELF header, program headers and dynamic section of the
+    /// image mapped at `base`.
+    ///
+    /// Only little endian images are supported
+    pub fn read<P: ProcessTraits>(
+        process: &P,
+        base: usize,
+    ) -> Result<Self, ProcessError> {
+        let mut header = [0u8; 0x40];
+        process.read(base, header.len(), &mut header)?;
+
+        if &header[..4] != ELF_MAGIC {
+            return Err(ProcessError::InvalidImage("bad ELF signature"));
+        }
+
+        if header[5] != DATA_LITTLE_ENDIAN {
+            return Err(ProcessError::InvalidImage("big endian image"));
+        }
+
+        let width = match header[4] {
+            CLASS_32 => PointerWidth::X32,
+            CLASS_64 => PointerWidth::X64,
+            _ => return Err(ProcessError::InvalidImage("unknown ELF class")),
+        };
+
+        let machine = u16_at(&header, 18)?;
+
+        let (entry, phoff, phentsize, phnum) = match width {
+            PointerWidth::X32 => (
+                u32_at(&header, 24)? as usize,
+                u32_at(&header, 28)? as usize,
+                u16_at(&header, 42)? as usize,
+                u16_at(&header, 44)? as usize,
+            ),
+            PointerWidth::X64 => (
+                u64_at(&header, 24)? as usize,
+                u64_at(&header, 32)? as usize,
+                u16_at(&header, 54)? as usize,
+                u16_at(&header, 56)? as usize,
+            ),
+        };
+
+        if phnum > MAX_SEGMENTS || phentsize < ph_size(width) {
+            return Err(ProcessError::InvalidImage("bad program headers"));
+        }
+
+        let mut table = vec![0u8; phnum * phentsize];
+        process.read(base + phoff, table.len(), &mut table)?;
+
+        // (kind, flags, vaddr, memsz)
+        let headers = table
+            .chunks_exact(phentsize)
+            .map(|ph| match width {
+                PointerWidth::X32 => Ok((
+                    u32_at(ph, 0)?,
+                    u32_at(ph, 24)?,
+                    u32_at(ph, 8)? as usize,
+                    u32_at(ph, 20)? as usize,
+                )),
+                PointerWidth::X64 => Ok((
+                    u32_at(ph, 0)?,
+                    u32_at(ph, 4)?,
+                    u64_at(ph, 16)? as usize,
+                    u64_at(ph, 40)? as usize,
+                )),
+            })
+            .collect::<Result<Vec<_>, ProcessError>>()?;
+
+        // Image base is the start of the first loadable segment
+        let first_load = headers
+            .iter()
+            .filter(|(kind, ..)| *kind == SEGMENT_LOAD)
+            .map(|(_, _, vaddr, _)| vaddr & !(PAGE_SIZE - 1))
+            .min()
+            .ok_or(ProcessError::InvalidImage("no loadable segments"))?;
+
+        let bias = base.wrapping_sub(first_load);
+
+        let segments: Vec<Segment> = headers
+            .into_iter()
+            .map(|(kind, flags, vaddr, size)| Segment {
+                kind,
+                flags,
+                addr: bias.wrapping_add(vaddr),
+                size,
+            })
+            .collect();
+
+        let dynamic = segments
+            .iter()
+            .find(|segment| segment.kind == SEGMENT_DYNAMIC)
+            .map(|segment| read_dynamic(process, segment, width, base, bias))
+            .transpose()?;
+
+        Ok(Self {
+            base,
+            bias,
+            machine,
+            width,
+            entry_point: (entry != 0).then(|| bias.wrapping_add(entry)),
+            segments,
+            dynamic,
+        })
+    }
+
+    /// Reads every defined symbol from the dynamic symbol table.
+    ///
+    /// Returns empty `Vec` if image doesn't have dynamic section
+    pub fn symbols<P: ProcessTraits>(
+        &self,
+        process: &P,
+    ) -> Result<Vec<Symbol>, ProcessError> {
+        let Some(dynamic) = self.dynamic else {
+            return Ok(Vec::new());
+        };
+
+        let count = match (dynamic.gnu_hash, dynamic.hash) {
+            (Some(gnu_hash), _) => {
+                gnu_hash_symbols(process, gnu_hash, self.width)?
+            }
+            (None, Some(hash)) => process.read_u32(hash + 4)? as usize,
+            (None, None) => {
+                return Err(ProcessError::InvalidImage("no symbol hash table"))
+            }
+        };
+
+        if count > MAX_SYMBOLS || dynamic.strsz > MAX_STRINGS {
+            return Err(ProcessError::InvalidImage("symbol table is too big"));
+        }
+
+        let entry_size = match self.width {
+            PointerWidth::X32 => 16,
+            PointerWidth::X64 => 24,
+        };
+
+        let mut table = vec![0u8; count * entry_size];
+        process.read(dynamic.symtab, table.len(), &mut table)?;
+
+        let mut strings = vec![0u8; dynamic.strsz];
+        process.read(dynamic.strtab, strings.len(), &mut strings)?;
+
+        let mut symbols = Vec::new();
+
+        for entry in table.chunks_exact(entry_size) {
+            let (name, info, section, value, size) = match self.width {
+                PointerWidth::X32 => (
+                    u32_at(entry, 0)? as usize,
+                    entry[12],
+                    u16_at(entry, 14)?,
+                    u32_at(entry, 4)? as usize,
+                    u32_at(entry, 8)? as usize,
+                ),
+                PointerWidth::X64 => (
+                    u32_at(entry, 0)? as usize,
+                    entry[4],
+                    u16_at(entry, 6)?,
+                    u64_at(entry, 8)? as usize,
+                    u64_at(entry, 16)? as usize,
+                ),
+            };
+
+            // Undefined symbols are imports from other modules
+            if section == 0 || value == 0 {
+                continue;
+            }
+
+            let Some(name) = strings.get(name..) else {
+                return Err(ProcessError::InvalidImage(
+                    "symbol name is out of bounds",
+                ));
+            };
+
+            let len = memchr::memchr(0, name).unwrap_or(name.len());
+
+            symbols.push(Symbol {
+                name: String::from_utf8_lossy(&name[..len]).into_owned(),
+                addr: self.bias.wrapping_add(value),
+                size,
+                kind: info & 0xF,
+            });
+        }
+
+        Ok(symbols)
+    }
+
+    /// Finds address of the defined dynamic symbol
+    pub fn symbol<P: ProcessTraits>(
+        &self,
+        process: &P,
+        name: &str,
+    ) -> Result<Option<usize>, ProcessError> {
+        Ok(self
+            .symbols(process)?
+            .into_iter()
+            .find(|symbol| symbol.name == name)
+            .map(|symbol| symbol.addr))
+    }
+}
+
+fn ph_size(width: PointerWidth) -> usize {
+    match width {
+        PointerWidth::X32 => 32,
+        PointerWidth::X64 => 56,
+    }
+}
+
+fn read_dynamic<P: ProcessTraits>(
+    process: &P,
+    segment: &Segment,
+    width: PointerWidth,
+    base: usize,
+    bias: usize,
+) -> Result<Dynamic, ProcessError> {
+    let entry_size = width.size() * 2;
+    let count = (segment.size / entry_size).min(MAX_DYNAMIC);
+
+    let mut table = vec![0u8; count * entry_size];
+    process.read(segment.addr, table.len(), &mut table)?;
+
+    // Dynamic linker usually relocates pointers inside of the dynamic
+    // section in place, but not on every platform
+    let relocate = |ptr: usize| {
+        if ptr < base {
+            bias.wrapping_add(ptr)
+        } else {
+            ptr
+        }
+    };
+
+    let mut dynamic = Dynamic::default();
+
+    for entry in table.chunks_exact(entry_size) {
+        let (tag, value) = match width {
+            PointerWidth::X32 => {
+                (u32_at(entry, 0)? as u64, u32_at(entry, 4)? as usize)
+            }
+            PointerWidth::X64 => {
+                (u64_at(entry, 0)?, u64_at(entry, 8)? as usize)
+            }
+        };
+
+        match tag {
+            DT_NULL => break,
+            DT_HASH => dynamic.hash = Some(relocate(value)),
+            DT_GNU_HASH => dynamic.gnu_hash = Some(relocate(value)),
+            DT_STRTAB => dynamic.strtab = relocate(value),
+            DT_SYMTAB => dynamic.symtab = relocate(value),
+            DT_STRSZ => dynamic.strsz = value,
+            _ => (),
+        }
+    }
+
+    if dynamic.symtab == 0 || dynamic.strtab == 0 {
+        return Err(ProcessError::InvalidImage("no dynamic symbol table"));
+    }
+
+    Ok(dynamic)
+}
+
+/// GNU hash table doesn't store amount of symbols, so it's calculated
+/// from the last symbol of the longest bucket chain
+fn gnu_hash_symbols<P: ProcessTraits>(
+    process: &P,
+    addr: usize,
+    width: PointerWidth,
+) -> Result<usize, ProcessError> {
+    let mut header = [0u8; 16];
+    process.read(addr, header.len(), &mut header)?;
+
+    let buckets_count = u32_at(&header, 0)? as usize;
+    let sym_offset = u32_at(&header, 4)? as usize;
+    let bloom_size = u32_at(&header, 8)? as usize;
+
+    if buckets_count > MAX_SYMBOLS || bloom_size > MAX_SYMBOLS {
+        return Err(ProcessError::InvalidImage("bad GNU hash table"));
+    }
+
+    let buckets_addr = addr + 16 + bloom_size * width.size();
+
+    let mut buckets: Vec<u32> = Vec::new();
+    process.read_pod_array(buckets_addr, buckets_count, &mut buckets)?;
+
+    let Some(last) = buckets.into_iter().max().map(|last| last as usize) else {
+        return Ok(sym_offset);
+    };
+
+    if last < sym_offset {
+        return Ok(sym_offset);
+    }
+
+    let chains_addr = buckets_addr + buckets_count * 4;
+    let mut index = last;
+
+    // Last entry of the chain has lowest bit set
+    while process.read_u32(chains_addr + (index - sym_offset) * 4)? & 1 == 0 {
+        index += 1;
+
+        if index > MAX_SYMBOLS {
+            return Err(ProcessError::InvalidImage("bad GNU hash table"));
+        }
+    }
+
+    Ok(index + 1)
+}
@@ -1,4 +1,4 @@
-use std::{num::ParseIntError, string::FromUtf8Error};
+use std::string::FromUtf8Error;
 
 #[derive(thiserror::Error, Debug)]
 pub enum ProcessError {
@@ -18,10 +18,31 @@ pub enum ProcessError {
     BadAddress(usize, usize),
     #[error("cannot find signature: {0}")]
     SignatureNotFound(String),
+    #[error("signature is ambiguous, found {0} matches")]
+    SignatureAmbiguous(usize),
+    #[error("signature `{0}` has no capture")]
+    SignatureNoCapture(String),
+    #[error("capture of `{0}` bytes can't be decoded")]
+    InvalidCaptureLength(usize),
+    #[error("module `{0}` is not mapped")]
+    ModuleNotFound(String),
+    #[error("invalid executable image: {0}")]
+    InvalidImage(&'static str),
+    #[error("collection size `{size}` is over its capacity `{capacity}`")]
+    InvalidCollectionSize { size: usize, capacity: usize },
     #[error("failed to convert address to usize")]
     AddressConvertError,
     #[error("string is too large, over the limit")]
     StringTooLarge,
+    #[error("array length `{0}` is over the limit")]
+    ArrayTooLarge(usize),
+    #[error("pointer chain is broken at hop {hop}, addr: {addr:X}")]
+    BrokenPointerChain {
+        hop: usize,
+        addr: usize,
+        #[source]
+        source: Box<ProcessError>,
+    },
     #[cfg(target_os = "linux")]
     #[error("os error `{0}`")]
     OsError(#[from] nix::errno::Errno),
@@ -50,8 +71,40 @@ impl From<std::str::Utf8Error> for ProcessError {
 
 #[derive(thiserror::Error, Debug)]
 pub enum ParseSignatureError {
-    #[error("invalid string length `{0}`")]
-    InvalidLength(usize),
-    #[error("failed to parse integer")]
-    InvalidInt(#[from] ParseIntError),
+    #[error("signature is empty")]
+    Empty,
+    #[error("invalid token `{token}` at position {position}")]
+    InvalidToken { position: usize, token: String },
+    #[error(
+        "invalid capture at position {0}, expected exactly one `[..]` group"
+    )]
+    InvalidCapture(usize),
+}
+
+#[cfg(feature = "database")]
+#[derive(thiserror::Error, Debug)]
+pub enum DatabaseError {
+    #[error("io error")]
+    IoError(#[from] std::io::Error),
+    #[error("failed to parse signature set")]
+    Toml(#[from] toml::de::Error),
+    #[error("failed to serialize signature cache")]
+    TomlSerialize(#[from] toml::ser::Error),
+    #[error("invalid pattern of `{name}`")]
+    InvalidPattern {
+        name: String,
+        #[source]
+        source: ParseSignatureError,
+    },
+    #[error("invalid pointer width `{width}` of `{name}`, expected 32 or 64")]
+    InvalidWidth { name: String, width: u32 },
+    #[error("invalid game version `{0}`")]
+    InvalidVersion(String),
+    #[error(transparent)]
+    Process(#[from] ProcessError),
+    #[error(
+        "failed to resolve signatures: {}",
+        .0.iter().map(|s| s.name.as_str()).collect::<Vec<_>>().join(", ")
+    )]
+    UnresolvedSignatures(Vec<crate::database::UnresolvedSignature>),
 }
@@ -0,0 +1,20 @@
+//! Helpers shared by [`crate::pe`] and [`crate::elf`] parsers
+use crate::error::ProcessError;
+
+pub(crate) fn u16_at(buff: &[u8], offset: usize) -> Result<u16, ProcessError> {
+    buff.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or(ProcessError::InvalidImage("header is truncated"))
+}
+
+pub(crate) fn u32_at(buff: &[u8], offset: usize) -> Result<u32, ProcessError> {
+    buff.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(ProcessError::InvalidImage("header is truncated"))
+}
+
+pub(crate) fn u64_at(buff: &[u8], offset: usize) -> Result<u64, ProcessError> {
+    buff.get(offset..offset + 8)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(ProcessError::InvalidImage("header is truncated"))
+}
@@ -1,5 +1,19 @@
+pub mod arch;
+pub mod batch;
+#[cfg(feature = "database")]
+pub mod cache;
+#[cfg(feature = "database")]
+pub mod database;
+pub mod elf;
 pub mod error;
+mod image;
+pub mod module;
+pub mod pe;
+pub mod pod;
+pub mod pointer;
 pub mod process;
+pub mod region;
+pub mod remote;
 pub mod signature;
 
 cfg_if::cfg_if! {
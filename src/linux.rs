@@ -1,17 +1,27 @@
-use std::{fs, io::IoSliceMut, path::PathBuf};
+use std::{
+    fs::{self, OpenOptions},
+    io::{IoSlice, IoSliceMut},
+    os::unix::fs::FileExt,
+    path::{Path, PathBuf},
+};
 
 use nix::{
     errno::Errno,
-    sys::uio::{process_vm_readv, RemoteIoVec},
+    sys::uio::{process_vm_readv, process_vm_writev, RemoteIoVec},
     unistd::Pid,
 };
 
 use crate::{
+    arch::TargetArch,
+    batch::ReadBatch,
     error::ProcessError,
     process::{MemoryRegion, Process, ProcessTraits},
+    region::{Protection, RegionKind},
 };
 
-use super::signature::{find_signature, Signature};
+/// Maximum amount of iovecs accepted by `process_vm_readv`
+/// (`UIO_MAXIOV` in kernel)
+const IOV_MAX: usize = 1024;
 
 impl ProcessTraits for Process {
     fn initialize(
@@ -41,7 +51,7 @@ impl ProcessTraits for Process {
                 continue;
             }
 
-            let mut cmd_buff = fs::read_to_string(cmd_line)?;
+            let cmd_buff = fs::read_to_string(cmd_line)?;
 
             let line = cmd_buff.split(' ').next().unwrap();
 
@@ -55,16 +65,15 @@ impl ProcessTraits for Process {
                 let stat = p.join("stat");
                 let buff = fs::read_to_string(stat)?;
 
-                // Formatting path
-                cmd_buff.retain(|c| c != '\0');
-                cmd_buff = cmd_buff.replace('\\', "/");
-
-                cmd_buff.remove(0);
-                cmd_buff.remove(0);
+                // Arguments are separated by NUL and only the first one
+                // is the executable
+                let argv0 = cmd_buff.split('\0').next().unwrap_or_default();
 
-                let executable_path = PathBuf::from(cmd_buff);
-                let executable_dir =
-                    executable_path.parent().map(|v| v.to_path_buf());
+                let executable_path = executable_path(&p, argv0);
+                let executable_dir = executable_path
+                    .as_ref()
+                    .and_then(|path| path.parent())
+                    .map(|v| v.to_path_buf());
 
                 let pid_str = buff.split(' ').next().unwrap();
 
@@ -74,6 +83,9 @@ impl ProcessTraits for Process {
                     pid,
                     maps: Vec::new(),
                     executable_dir,
+                    executable_path,
+                    arch: TargetArch::HOST,
+                    arch_detected: false,
                 });
             }
         }
@@ -92,70 +104,51 @@ impl ProcessTraits for Process {
                 break;
             }
 
-            let mut split = line.split_whitespace();
-            let range_raw = split.next().unwrap();
-            let mut permissions_raw_chars = split.next().unwrap().chars();
-
-            let mut range_split = range_raw.split('-');
-
-            let from_str = range_split.next().unwrap();
-            let to_str = range_split.next().unwrap();
-
-            let from = usize::from_str_radix(from_str, 16)?;
-            let to = usize::from_str_radix(to_str, 16)?;
-
-            let read = permissions_raw_chars.next().unwrap();
-            let write = permissions_raw_chars.next().unwrap();
-
-            if read == 'r' && write == 'w' {
-                v.push(MemoryRegion {
-                    from,
-                    size: to - from,
-                });
-            }
+            v.push(parse_maps_line(line)?);
         }
 
         self.maps = v;
+        if let Some(arch) = self.detect_arch() {
+            self.arch = arch;
+            self.arch_detected = true;
+        }
+
         Ok(self)
     }
 
-    fn read_signature<T: TryFrom<usize>>(
-        &self,
-        sign: &Signature,
-    ) -> Result<T, ProcessError> {
-        let mut buff = Vec::new();
-
-        for region in &self.maps {
-            let remote = RemoteIoVec {
-                base: region.from,
-                len: region.size,
-            };
+    fn regions(&self) -> &[MemoryRegion] {
+        &self.maps
+    }
 
-            buff.resize(region.size, 0);
+    fn arch(&self) -> TargetArch {
+        self.arch
+    }
 
-            let slice = IoSliceMut::new(buff.as_mut_slice());
+    fn read_region(
+        &self,
+        region: &MemoryRegion,
+        buff: &mut Vec<u8>,
+    ) -> Result<bool, ProcessError> {
+        let remote = RemoteIoVec {
+            base: region.from,
+            len: region.size,
+        };
 
-            let res = process_vm_readv(
-                Pid::from_raw(self.pid),
-                &mut [slice],
-                &[remote],
-            );
+        buff.resize(region.size, 0);
 
-            if let Err(e) = res {
-                match e {
-                    Errno::EPERM | Errno::ESRCH => return Err(e.into()),
-                    _ => continue,
-                }
-            }
+        let slice = IoSliceMut::new(buff.as_mut_slice());
 
-            if let Some(offset) = find_signature(buff.as_slice(), sign) {
-                return (remote.base + offset)
-                    .try_into()
-                    .map_err(|_| ProcessError::AddressConvertError);
+        let res =
+            process_vm_readv(Pid::from_raw(self.pid), &mut [slice], &[remote]);
+
+        match res {
+            Ok(n) => {
+                buff.truncate(n);
+                Ok(true)
             }
+            Err(e @ (Errno::EPERM | Errno::ESRCH)) => Err(e.into()),
+            Err(_) => Ok(false),
         }
-
-        Err(ProcessError::SignatureNotFound(sign.to_string()))
     }
 
     fn read<T: TryInto<usize>>(
@@ -187,4 +180,267 @@ impl ProcessTraits for Process {
 
         Ok(())
     }
+
+    fn read_batch(
+        &self,
+        batch: &mut ReadBatch<'_>,
+    ) -> Result<Vec<Result<(), ProcessError>>, ProcessError> {
+        let entries = batch.entries_mut();
+        let total = entries.len();
+
+        let mut results = Vec::with_capacity(total);
+        let mut remotes = Vec::with_capacity(total.min(IOV_MAX));
+
+        while results.len() < total {
+            let start = results.len();
+            let chunk = &mut entries[start..total.min(start + IOV_MAX)];
+
+            remotes.clear();
+            remotes.extend(chunk.iter().map(|entry| RemoteIoVec {
+                base: entry.addr,
+                len: entry.buff.len(),
+            }));
+
+            let mut slices: Vec<IoSliceMut> = chunk
+                .iter_mut()
+                .map(|entry| IoSliceMut::new(entry.buff))
+                .collect();
+
+            let res = process_vm_readv(
+                Pid::from_raw(self.pid),
+                &mut slices,
+                &remotes,
+            );
+
+            let mut read = match res {
+                Ok(read) => read,
+                Err(e @ (Errno::EPERM | Errno::ESRCH)) => return Err(e.into()),
+                // Nothing was transferred, so the very first entry failed
+                Err(Errno::EFAULT) => {
+                    let remote = &remotes[0];
+                    results.push(Err(ProcessError::BadAddress(
+                        remote.base,
+                        remote.len,
+                    )));
+                    continue;
+                }
+                Err(e) => {
+                    results.push(Err(e.into()));
+                    continue;
+                }
+            };
+
+            // Transfers never split a single iovec, so every entry
+            // covered by `read` bytes is fully read and the one right
+            // after them is the one that failed. Reading continues
+            // from the next entry.
+            for remote in &remotes {
+                if read < remote.len {
+                    results.push(Err(ProcessError::BadAddress(
+                        remote.base,
+                        remote.len,
+                    )));
+                    break;
+                }
+
+                read -= remote.len;
+                results.push(Ok(()));
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn write<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        buff: &[u8],
+    ) -> Result<(), ProcessError> {
+        let addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        let remote = RemoteIoVec {
+            base: addr,
+            len: buff.len(),
+        };
+
+        let slice = IoSlice::new(buff);
+
+        let res =
+            process_vm_writev(Pid::from_raw(self.pid), &[slice], &[remote]);
+
+        // `process_vm_writev` respects page protection, so writing
+        // into read-only pages fails with EFAULT or stops at the first
+        // one. `/proc/<pid>/mem` is allowed to bypass it.
+        match res {
+            Ok(n) if n == buff.len() => Ok(()),
+            Ok(n) => self.write_proc_mem(addr + n, &buff[n..]),
+            Err(Errno::EFAULT) => self.write_proc_mem(addr, buff),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Path of the executable image of the process at `proc_dir`.
+///
+/// Under wine `/proc/<pid>/exe` is the wine loader, so windows path
+/// from `argv0` is used with the drive letter stripped, e.g.
+/// `Z:\home\user\osu!\osu!.exe` is `/home/user/osu!/osu!.exe`.
+/// Native processes are resolved through `/proc/<pid>/exe`
+fn executable_path(proc_dir: &Path, argv0: &str) -> Option<PathBuf> {
+    if let Some(path) = strip_drive(argv0) {
+        return Some(PathBuf::from(path.replace('\\', "/")));
+    }
+
+    fs::read_link(proc_dir.join("exe"))
+        .ok()
+        .or_else(|| (!argv0.is_empty()).then(|| PathBuf::from(argv0)))
+}
+
+/// Strips `X:` prefix of a windows path, `None` if there is none
+fn strip_drive(path: &str) -> Option<&str> {
+    match path.as_bytes() {
+        [drive, b':', b'\\' | b'/', ..] if drive.is_ascii_alphabetic() => {
+            Some(&path[2..])
+        }
+        _ => None,
+    }
+}
+
+/// Parses a single line of `/proc/<pid>/maps`
+///
+/// Format: `address perms offset dev inode pathname`, where pathname is
+/// padded with spaces and might be missing
+fn parse_maps_line(line: &str) -> Result<MemoryRegion, ProcessError> {
+    let mut split = line.splitn(6, ' ');
+
+    let range_raw = split.next().unwrap_or_default();
+    let mut permissions_raw_chars = split.next().unwrap_or_default().chars();
+
+    // offset, dev and inode
+    split.nth(2);
+
+    let pathname = split.next().unwrap_or_default().trim_start();
+
+    let mut range_split = range_raw.split('-');
+
+    let from_str = range_split.next().unwrap_or_default();
+    let to_str = range_split.next().unwrap_or_default();
+
+    let from = usize::from_str_radix(from_str, 16)?;
+    let to = usize::from_str_radix(to_str, 16)?;
+
+    let protection = Protection::new(
+        permissions_raw_chars.next() == Some('r'),
+        permissions_raw_chars.next() == Some('w'),
+        permissions_raw_chars.next() == Some('x'),
+    );
+
+    let (kind, path) = match pathname {
+        "" => (RegionKind::Anonymous, None),
+        "[heap]" => (RegionKind::Heap, None),
+        p if p.starts_with("[stack") => (RegionKind::Stack, None),
+        p if p.starts_with("[anon") => (RegionKind::Anonymous, None),
+        p if p.starts_with('[') => (RegionKind::Other, None),
+        p => (RegionKind::File, Some(PathBuf::from(p))),
+    };
+
+    Ok(MemoryRegion {
+        from,
+        size: to - from,
+        protection,
+        kind,
+        path,
+    })
+}
+
+impl Process {
+    /// Architecture of the executable image, requires memory regions
+    /// to be read
+    fn detect_arch(&self) -> Option<TargetArch> {
+        let name = self.executable_path.as_ref()?.file_name()?.to_str()?;
+
+        TargetArch::detect(self, name)
+    }
+
+    fn write_proc_mem(
+        &self,
+        addr: usize,
+        buff: &[u8],
+    ) -> Result<(), ProcessError> {
+        let path = format!("/proc/{}/mem", &self.pid);
+
+        let file = OpenOptions::new().write(true).open(path)?;
+
+        file.write_all_at(buff, addr as u64).map_err(|e| {
+            match e.raw_os_error().map(Errno::from_i32) {
+                Some(Errno::EFAULT | Errno::EIO) => {
+                    ProcessError::BadAddress(addr, buff.len())
+                }
+                _ => e.into(),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{parse_maps_line, strip_drive};
+    use crate::region::{Protection, RegionKind};
+
+    #[test]
+    fn test_parse_maps_line() {
+        let region = parse_maps_line(
+            "5581e9a00000-5581e9a21000 rw-p 00000000 00:00 0                          [heap]",
+        )
+        .unwrap();
+
+        assert_eq!(region.from, 0x5581e9a00000);
+        assert_eq!(region.size, 0x21000);
+        assert_eq!(region.protection, Protection::new(true, true, false));
+        assert_eq!(region.kind, RegionKind::Heap);
+        assert_eq!(region.path, None);
+
+        let region = parse_maps_line(
+            "7f1c2a000000-7f1c2a100000 r-xp 00001000 08:01 1234   /home/user/osu!/osu!.exe",
+        )
+        .unwrap();
+
+        assert_eq!(region.protection, Protection::new(true, false, true));
+        assert_eq!(region.kind, RegionKind::File);
+        assert_eq!(
+            region.path,
+            Some(PathBuf::from("/home/user/osu!/osu!.exe"))
+        );
+
+        let region =
+            parse_maps_line("7f1c2a000000-7f1c2a100000 ---p 00000000 00:00 0")
+                .unwrap();
+
+        assert_eq!(region.protection, Protection::default());
+        assert_eq!(region.kind, RegionKind::Anonymous);
+
+        let region = parse_maps_line(
+            "7ffd5a1e0000-7ffd5a201000 rw-p 00000000 00:00 0                          [stack]",
+        )
+        .unwrap();
+
+        assert_eq!(region.kind, RegionKind::Stack);
+    }
+
+    #[test]
+    fn test_strip_drive() {
+        assert_eq!(
+            strip_drive("Z:\\home\\user\\osu!\\osu!.exe"),
+            Some("\\home\\user\\osu!\\osu!.exe")
+        );
+        assert_eq!(strip_drive("c:/osu!/osu!.exe"), Some("/osu!/osu!.exe"));
+        assert_eq!(strip_drive("/tmp/.mount_x/osu!"), None);
+        assert_eq!(strip_drive("osu!.exe"), None);
+        assert_eq!(strip_drive("Z:"), None);
+        assert_eq!(strip_drive(""), None);
+    }
 }
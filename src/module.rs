@@ -0,0 +1,72 @@
+use std::{ops::Range, path::PathBuf};
+
+use crate::{process::MemoryRegion, region::RegionKind};
+
+/// File mapped into process memory, like executable image or shared
+/// library, see [`crate::process::ProcessTraits::modules()`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Module {
+    /// File name, for example `osu!.exe` or `libSDL2.so`
+    pub name: String,
+    pub path: PathBuf,
+    /// Address of the first mapping
+    pub base: usize,
+    /// Size from the base to the end of the last mapping
+    pub size: usize,
+}
+
+impl Module {
+    /// Memory occupied by the module, can be passed to
+    /// [`crate::process::ProcessTraits::read_signature_in()`]
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.base..self.base + self.size
+    }
+
+    #[inline]
+    pub fn contains(&self, addr: usize) -> bool {
+        self.range().contains(&addr)
+    }
+}
+
+/// Collapses consecutive file mappings with the same path into modules
+///
+/// Anonymous regions between mappings of the same file don't split
+/// it, since loaders (wine in particular) map parts of the image like
+/// `.bss` or gaps between sections as anonymous memory
+pub(crate) fn collect_modules(regions: &[MemoryRegion]) -> Vec<Module> {
+    let mut modules: Vec<Module> = Vec::new();
+    let mut last_path = None;
+
+    for region in regions {
+        let path = match (&region.kind, &region.path) {
+            (RegionKind::File, Some(path)) => path,
+            (RegionKind::Anonymous, _) => continue,
+            _ => {
+                last_path = None;
+                continue;
+            }
+        };
+
+        let end = region.from + region.size;
+
+        match modules.last_mut() {
+            Some(module) if last_path == Some(path) => {
+                module.size = end.max(module.base + module.size) - module.base;
+            }
+            _ => modules.push(Module {
+                name: path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+                path: path.clone(),
+                base: region.from,
+                size: region.size,
+            }),
+        }
+
+        last_path = Some(path);
+    }
+
+    modules
+}
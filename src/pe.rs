@@ -0,0 +1,298 @@
+//! Parsing of PE images (`.exe` / `.dll`) mapped into process memory,
+//! including images mapped by wine on linux
+//!
+//! ```no_run
+//! use rosu_mem::{
+//!     pe::PeImage,
+//!     process::{Process, ProcessTraits},
+//!     signature,
+//! };
+//!
+//! # fn main() -> Result<(), rosu_mem::error::ProcessError> {
+//! let process = Process::initialize("osu!.exe", &[])?;
+//! let module = process.module("osu!.exe").unwrap();
+//!
+//! let image = PeImage::read(&process, module.base)?;
+//! let text = image.section(".text").unwrap();
+//!
+//! let addr: usize = process
+//!     .read_signature_in(&signature!("F8 01 74 04 83 65"), text.range())?;
+//! # Ok(())
+//! # }
+//! ```
+use std::ops::Range;
+
+use crate::{
+    error::ProcessError,
+    image::{u16_at, u32_at, u64_at},
+    pointer::PointerWidth,
+    process::ProcessTraits,
+};
+
+pub(crate) const DOS_MAGIC: &[u8; 2] = b"MZ";
+const NT_MAGIC: &[u8; 4] = b"PE\0\0";
+
+const PE32_MAGIC: u16 = 0x10B;
+const PE32_PLUS_MAGIC: u16 = 0x20B;
+
+const FILE_HEADER_SIZE: usize = 20;
+const SECTION_HEADER_SIZE: usize = 40;
+
+/// Windows loader refuses images with more sections than this
+const MAX_SECTIONS: usize = 96;
+/// Exports are addressed by 16 bit ordinals
+const MAX_EXPORTS: usize = 0x10000;
+/// Longest export name read before giving up on finding its NUL
+const MAX_NAME_LEN: usize = 512;
+
+pub const SECTION_EXECUTE: u32 = 0x2000_0000;
+pub const SECTION_READ: u32 = 0x4000_0000;
+pub const SECTION_WRITE: u32 = 0x8000_0000;
+
+/// Section of a [`PeImage`], addresses are already relocated to the
+/// actual image base
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// Name like `.text` or `.data`, up to 8 characters
+    pub name: String,
+    pub addr: usize,
+    pub size: usize,
+    /// `IMAGE_SCN_*` flags, see [`SECTION_EXECUTE`], [`SECTION_READ`]
+    /// and [`SECTION_WRITE`]
+    pub characteristics: u32,
+}
+
+impl Section {
+    /// Memory occupied by the section, can be passed to
+    /// [`ProcessTraits::read_signature_in()`]
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.addr..self.addr + self.size
+    }
+
+    #[inline]
+    pub fn is_executable(&self) -> bool {
+        self.characteristics & SECTION_EXECUTE != 0
+    }
+
+    #[inline]
+    pub fn is_writable(&self) -> bool {
+        self.characteristics & SECTION_WRITE != 0
+    }
+}
+
+/// Named export of a [`PeImage`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Export {
+    pub name: String,
+    pub ordinal: u32,
+    /// Address of the exported item. For forwarded exports it points
+    /// to the forwarder string like `NTDLL.RtlAllocateHeap`
+    pub addr: usize,
+}
+
+/// Headers of a PE image read from process memory
+#[derive(Debug, Clone)]
+pub struct PeImage {
+    /// Address where image is actually mapped
+    pub base: usize,
+    /// `IMAGE_FILE_MACHINE_*` value, `0x14C` for x86 and `0x8664`
+    /// for x86-64
+    pub machine: u16,
+    /// PE32 images use 32 bit pointers and PE32+ 64 bit ones
+    pub width: PointerWidth,
+    /// Preferred base address from the optional header
+    pub image_base: u64,
+    pub size_of_image: usize,
+    /// Address of the entry point, `None` if image doesn't have one
+    pub entry_point: Option<usize>,
+    pub sections: Vec<Section>,
+    /// RVA and size of the export directory
+    exports: Option<(u32, u32)>,
+}
+
+impl PeImage {
+    /// Reads DOS and NT headers together with section table of the
+    /// image mapped at `base`
+    pub fn read<P: ProcessTraits>(
+        process: &P,
+        base: usize,
+    ) -> Result<Self, ProcessError> {
+        let mut dos = [0u8; 0x40];
+        process.read(base, dos.len(), &mut dos)?;
+
+        if &dos[..2] != DOS_MAGIC {
+            return Err(ProcessError::InvalidImage("bad DOS signature"));
+        }
+
+        let nt_offset = u32_at(&dos, 0x3C)? as usize;
+        let nt_addr = base + nt_offset;
+
+        let mut nt = [0u8; 4 + FILE_HEADER_SIZE];
+        process.read(nt_addr, nt.len(), &mut nt)?;
+
+        if &nt[..4] != NT_MAGIC {
+            return Err(ProcessError::InvalidImage("bad NT signature"));
+        }
+
+        let machine = u16_at(&nt, 4)?;
+        let sections_count = u16_at(&nt, 6)? as usize;
+        let optional_size = u16_at(&nt, 20)? as usize;
+
+        if sections_count > MAX_SECTIONS {
+            return Err(ProcessError::InvalidImage("too many sections"));
+        }
+
+        let optional_addr = nt_addr + nt.len();
+        let mut optional = vec![0u8; optional_size];
+        process.read(optional_addr, optional.len(), &mut optional)?;
+
+        // Offsets of the fields that differ between PE32 and PE32+
+        let (width, image_base, directories) = match u16_at(&optional, 0)? {
+            PE32_MAGIC => {
+                (PointerWidth::X32, u32_at(&optional, 28)? as u64, 92)
+            }
+            PE32_PLUS_MAGIC => (PointerWidth::X64, u64_at(&optional, 24)?, 108),
+            _ => {
+                return Err(ProcessError::InvalidImage(
+                    "unknown optional header magic",
+                ))
+            }
+        };
+
+        let entry_point = match u32_at(&optional, 16)? {
+            0 => None,
+            rva => Some(base + rva as usize),
+        };
+
+        let size_of_image = u32_at(&optional, 56)? as usize;
+
+        // Export directory is the first one, after directories count
+        let exports = match u32_at(&optional, directories)? {
+            0 => None,
+            _ => match (
+                u32_at(&optional, directories + 4)?,
+                u32_at(&optional, directories + 8)?,
+            ) {
+                (0, _) => None,
+                (rva, size) => Some((rva, size)),
+            },
+        };
+
+        let mut table = vec![0u8; sections_count * SECTION_HEADER_SIZE];
+        process.read(optional_addr + optional_size, table.len(), &mut table)?;
+
+        let sections = table
+            .chunks_exact(SECTION_HEADER_SIZE)
+            .map(|header| {
+                let name_len =
+                    header[..8].iter().position(|&c| c == 0).unwrap_or(8);
+
+                Ok(Section {
+                    name: String::from_utf8_lossy(&header[..name_len])
+                        .into_owned(),
+                    addr: base + u32_at(header, 12)? as usize,
+                    size: u32_at(header, 8)? as usize,
+                    characteristics: u32_at(header, 36)?,
+                })
+            })
+            .collect::<Result<_, ProcessError>>()?;
+
+        Ok(Self {
+            base,
+            machine,
+            width,
+            image_base,
+            size_of_image,
+            entry_point,
+            sections,
+            exports,
+        })
+    }
+
+    /// Finds section by its name, for example `.text`
+    pub fn section(&self, name: &str) -> Option<&Section> {
+        self.sections.iter().find(|section| section.name == name)
+    }
+
+    /// Memory occupied by the whole image
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.base..self.base + self.size_of_image
+    }
+
+    /// Reads every named export from the export directory.
+    ///
+    /// Returns empty `Vec` if image doesn't export anything, which is
+    /// the case for most executables, including .NET ones.
+    pub fn exports<P: ProcessTraits>(
+        &self,
+        process: &P,
+    ) -> Result<Vec<Export>, ProcessError> {
+        let Some((rva, _)) = self.exports else {
+            return Ok(Vec::new());
+        };
+
+        let mut directory = [0u8; 40];
+        process.read(
+            self.base + rva as usize,
+            directory.len(),
+            &mut directory,
+        )?;
+
+        let ordinal_base = u32_at(&directory, 16)?;
+        let functions_count = u32_at(&directory, 20)? as usize;
+        let names_count = u32_at(&directory, 24)? as usize;
+        let functions = self.base + u32_at(&directory, 28)? as usize;
+        let names = self.base + u32_at(&directory, 32)? as usize;
+        let ordinals = self.base + u32_at(&directory, 36)? as usize;
+
+        if functions_count > MAX_EXPORTS || names_count > MAX_EXPORTS {
+            return Err(ProcessError::InvalidImage("too many exports"));
+        }
+
+        let mut name_rvas: Vec<u32> = Vec::new();
+        process.read_pod_array(names, names_count, &mut name_rvas)?;
+
+        let mut name_ordinals: Vec<u16> = Vec::new();
+        process.read_pod_array(ordinals, names_count, &mut name_ordinals)?;
+
+        let mut exports = Vec::with_capacity(names_count);
+
+        for (name_rva, index) in name_rvas.into_iter().zip(name_ordinals) {
+            let index = index as usize;
+
+            if index >= functions_count {
+                return Err(ProcessError::InvalidImage(
+                    "export ordinal is out of bounds",
+                ));
+            }
+
+            let function_rva = process.read_u32(functions + index * 4)?;
+
+            exports.push(Export {
+                name: process.read_c_string(
+                    self.base + name_rva as usize,
+                    MAX_NAME_LEN,
+                )?,
+                ordinal: ordinal_base.wrapping_add(index as u32),
+                addr: self.base + function_rva as usize,
+            });
+        }
+
+        Ok(exports)
+    }
+
+    /// Finds address of the named export
+    pub fn export<P: ProcessTraits>(
+        &self,
+        process: &P,
+        name: &str,
+    ) -> Result<Option<usize>, ProcessError> {
+        Ok(self
+            .exports(process)?
+            .into_iter()
+            .find(|export| export.name == name)
+            .map(|export| export.addr))
+    }
+}
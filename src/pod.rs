@@ -0,0 +1,41 @@
+/// Marker for "plain old data" types that can be safely
+/// created from any sequence of bytes
+///
+/// Used by [`crate::process::ProcessTraits::read_pod()`] to read
+/// whole structures from the process in one go.
+///
+/// # Safety
+/// Implementor must guarantee that:
+/// * Every possible bit pattern is a valid value of the type
+///   (so no `bool`, `char`, enums, references, etc.)
+/// * Type has stable layout, usually `#[repr(C)]` or
+///   `#[repr(transparent)]`
+/// * All fields are [`Pod`] too
+/// * Type has no padding bytes, add explicit fields instead, since
+///   values are written through a byte slice covering the whole type
+///
+/// ```
+/// use rosu_mem::pod::Pod;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Vector2 {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// unsafe impl Pod for Vector2 {}
+/// ```
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! pod_impl {
+    ($($t: ty),*) => {
+        $(unsafe impl Pod for $t {})*
+    };
+}
+
+pod_impl!(i8, i16, i32, i64, i128, isize);
+pod_impl!(u8, u16, u32, u64, u128, usize);
+pod_impl!(f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
@@ -0,0 +1,85 @@
+use crate::signature::Signature;
+
+/// Size of a pointer inside of target process
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PointerWidth {
+    /// 4 byte pointers (osu! stable)
+    X32,
+    /// 8 byte pointers (osu! lazer)
+    X64,
+}
+
+impl PointerWidth {
+    /// Size of a pointer in bytes
+    #[inline]
+    pub const fn size(self) -> usize {
+        match self {
+            PointerWidth::X32 => 4,
+            PointerWidth::X64 => 8,
+        }
+    }
+}
+
+/// Starting point of a [`PointerChain`]
+#[derive(Debug)]
+pub enum ChainBase {
+    /// Already known address
+    Address(usize),
+    /// Address of a signature match
+    Signature(Signature),
+}
+
+impl From<usize> for ChainBase {
+    fn from(value: usize) -> Self {
+        Self::Address(value)
+    }
+}
+
+impl From<Signature> for ChainBase {
+    fn from(value: Signature) -> Self {
+        Self::Signature(value)
+    }
+}
+
+/// Sequence of pointer dereferences starting from some base address.
+///
+/// Every offset is one hop: offset is added to the current address
+/// and pointer stored at the result becomes the new current address.
+/// Result of resolving the chain is the last read pointer.
+///
+/// ```
+/// use rosu_mem::pointer::{PointerChain, PointerWidth};
+///
+/// // Same as
+/// // let a = read_i64(base - 0x24);
+/// // let b = read_i64(a + 0x218);
+/// let chain = PointerChain::new(0x1000usize, PointerWidth::X64)
+///     .offset(-0x24)
+///     .offset(0x218);
+///
+/// assert_eq!(chain.offsets, [-0x24, 0x218]);
+/// ```
+///
+/// See [`crate::process::ProcessTraits::resolve_chain()`]
+#[derive(Debug)]
+pub struct PointerChain {
+    pub base: ChainBase,
+    pub offsets: Vec<isize>,
+    pub width: PointerWidth,
+}
+
+impl PointerChain {
+    pub fn new(base: impl Into<ChainBase>, width: PointerWidth) -> Self {
+        Self {
+            base: base.into(),
+            offsets: Vec::new(),
+            width,
+        }
+    }
+
+    /// Appends a new hop to the chain
+    pub fn offset(mut self, offset: isize) -> Self {
+        self.offsets.push(offset);
+        self
+    }
+}
@@ -1,27 +1,124 @@
-use std::path::PathBuf;
+use std::{ops::Range, path::PathBuf};
 
-use super::{error::ProcessError, signature::Signature};
+use super::{
+    arch::TargetArch,
+    batch::ReadBatch,
+    error::ProcessError,
+    module::{collect_modules, Module},
+    pod::Pod,
+    pointer::{ChainBase, PointerChain, PointerWidth},
+    region::{Protection, RegionFilter, RegionKind},
+    signature::{find_all_signatures, CaptureKind, Signature},
+};
 use paste::paste;
 
 #[cfg(target_os = "windows")]
 use windows::Win32::Foundation::HANDLE;
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone)]
 pub struct MemoryRegion {
     pub from: usize,
     pub size: usize,
+    pub protection: Protection,
+    pub kind: RegionKind,
+    /// Path to the backing file, if any
+    pub path: Option<PathBuf>,
 }
 
-macro_rules! read_generic {
-    ($t: ty, $self: expr, $addr: expr) => {{
-        paste! {
-            let mut bytes = vec![0u8; std::mem::size_of::<T>()];
+impl MemoryRegion {
+    /// Part of the region that is inside of `range`, if any
+    pub fn clip(&self, range: &Range<usize>) -> Option<MemoryRegion> {
+        let from = self.from.max(range.start);
+        let to = (self.from + self.size).min(range.end);
+
+        (from < to).then(|| MemoryRegion {
+            from,
+            size: to - from,
+            ..self.clone()
+        })
+    }
+
+    /// Checks if region is mapped from `module`, comparing file name
+    /// of the path ignoring ASCII case
+    pub fn belongs_to(&self, module: &str) -> bool {
+        self.path
+            .as_ref()
+            .and_then(|path| path.file_name())
+            .is_some_and(|name| name.eq_ignore_ascii_case(module))
+    }
+}
 
-            $self.read($addr, std::mem::size_of::<T>(), &mut bytes)?;
+/// Layout of the `Entry<TKey, TValue>` struct of a CLR `Dictionary`,
+/// all offsets are from the start of an entry.
+///
+/// Runtime reorders struct fields, so the layout depends on the
+/// types of keys and values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DictionaryEntry {
+    /// Size of one entry in the `_entries` array
+    pub size: usize,
+    /// Offset of the `hashCode` field
+    pub hash_code: usize,
+    /// Offset of the `next` field
+    pub next: usize,
+    /// Offset of the `key` field
+    pub key: usize,
+    /// Offset of the `value` field
+    pub value: usize,
+    /// Layout of .NET Framework (osu! stable), where `hashCode` is
+    /// masked to 31 bits and removed entries have it set to -1. On
+    /// .NET `hashCode` uses all 32 bits
+    pub framework: bool,
+}
 
-            unsafe { std::ptr::read(bytes.as_ptr() as *const T) }
+impl DictionaryEntry {
+    /// Layout of a dictionary where both keys and values are
+    /// references, like `Dictionary<string, Bindable>`. References go
+    /// first followed by `hashCode` and `next`.
+    ///
+    /// 32 bit layout is the .NET Framework one, 64 bit is .NET
+    pub const fn references(width: PointerWidth) -> Self {
+        let ptr = width.size();
+
+        Self {
+            size: ptr * 2 + 8,
+            hash_code: ptr * 2,
+            next: ptr * 2 + 4,
+            key: 0,
+            value: ptr,
+            framework: matches!(width, PointerWidth::X32),
         }
-    }};
+    }
+}
+
+/// Length and raw contents of a C# string, used by
+/// [`ProcessTraits::string_changed()`] to detect changes without
+/// decoding the string. Contents are only compared if the length is
+/// the same. Default value never matches any string
+#[derive(Debug, Default, Clone)]
+pub struct StringFingerprint {
+    units: Vec<u16>,
+    scratch: Vec<u16>,
+    read: bool,
+}
+
+impl StringFingerprint {
+    /// UTF-16 contents of the last read string
+    #[inline]
+    pub fn as_utf16(&self) -> &[u16] {
+        &self.units
+    }
+
+    /// Decodes the last read string into `buff`, reusing its memory.
+    /// Unpaired surrogates are replaced with
+    /// [`char::REPLACEMENT_CHARACTER`]
+    pub fn decode_into(&self, buff: &mut String) {
+        buff.clear();
+        buff.extend(
+            char::decode_utf16(self.units.iter().copied())
+                .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER)),
+        );
+    }
 }
 
 macro_rules! prim_read_impl {
@@ -40,38 +137,362 @@ macro_rules! prim_read_impl {
     };
 }
 
+macro_rules! prim_write_impl {
+    ($t: ident) => {
+        paste! {
+            fn [<write_ $t>]<T: TryInto<usize>>(
+                &self,
+                addr: T,
+                value: $t
+            ) -> Result<(), ProcessError> {
+                self.write(addr, &value.to_le_bytes())
+            }
+        }
+    };
+}
+
 macro_rules! prim_read_array_impl {
     ($t: ident) => {
         paste! {
+            /// Reads elements of a CLR `List<T>` located at `addr`
+            /// using layout of [`ProcessTraits::arch()`], see
+            /// [`ProcessTraits::read_list()`]. Use
+            /// [`ProcessTraits::read_array()`] for plain `T[]` arrays
             fn [<read_ $t _array>]<T: TryInto<usize>>(
                 &self,
                 addr: T,
                 buff: &mut Vec<$t>
             ) -> Result<(), ProcessError> {
-                let addr: usize = addr.try_into()
-                    .map_err(|_| ProcessError::AddressConvertError)?;
+                self.read_list(addr, buff)
+            }
+        }
+    };
+}
 
-                let items_ptr = self.read_i32(addr + 4)?;
-                let size = self.read_i32(addr + 12)? as usize;
+/// What to do after signature match is reported by [`scan_regions`]
+pub(crate) enum ScanFlow {
+    /// Keep looking for more matches
+    Continue,
+    /// Stop looking for this signature
+    Done,
+    /// Stop scanning completely
+    Stop,
+}
 
-                buff.resize(size, 0 as $t);
+/// Scans `regions` for every signature in `signs`, calling `on_match`
+/// with index of the signature and address of the match.
+///
+/// Matches of every signature are reported in ascending address order.
+/// Scanning stops when `on_match` returns [`ScanFlow::Stop`] or
+/// every signature is [`ScanFlow::Done`].
+///
+/// Last `longest signature - 1` bytes of every region are carried over
+/// to the next region if they are adjacent, so matches that straddle
+/// region boundary are found too.
+pub(crate) fn scan_regions<'a, P: ProcessTraits>(
+    process: &P,
+    regions: impl Iterator<Item = &'a MemoryRegion>,
+    signs: &[&Signature],
+    mut on_match: impl FnMut(usize, usize) -> ScanFlow,
+) -> Result<(), ProcessError> {
+    if signs.is_empty() {
+        return Ok(());
+    }
 
-                let byte_buff = unsafe { std::slice::from_raw_parts_mut(
-                    buff.as_mut_ptr() as *mut u8,
-                    buff.len() * std::mem::size_of::<$t>()
-                ) };
+    let overlap = signs.iter().map(|s| s.len()).max().unwrap_or(0);
+    let overlap = overlap.saturating_sub(1);
 
+    let mut buff = Vec::new();
 
-                self.read(
-                    items_ptr + 8,
-                    size * std::mem::size_of::<$t>(),
-                    byte_buff
-                )?;
+    // Bytes right before `tail_end` address, from previous regions
+    let mut tail: Vec<u8> = Vec::with_capacity(overlap * 2);
+    let mut tail_end = 0;
+
+    let mut seam = Vec::with_capacity(overlap * 2);
+
+    let mut done = vec![false; signs.len()];
+    let mut remaining = signs.len();
+
+    // Returns `true` if scanning should be stopped
+    let mut report =
+        |i: usize, addr: usize, done: &mut [bool]| match on_match(i, addr) {
+            ScanFlow::Continue => false,
+            ScanFlow::Done => {
+                done[i] = true;
+                remaining -= 1;
+                remaining == 0
+            }
+            ScanFlow::Stop => true,
+        };
+
+    for region in regions {
+        if !process.read_region(region, &mut buff)? {
+            tail.clear();
+            continue;
+        }
+
+        if region.from != tail_end {
+            tail.clear();
+        }
+
+        if !tail.is_empty() {
+            seam.clear();
+            seam.extend_from_slice(&tail);
+            seam.extend_from_slice(&buff[..overlap.min(buff.len())]);
+
+            let seam_start = tail_end - tail.len();
+
+            for (i, sign) in signs.iter().enumerate() {
+                if done[i] {
+                    continue;
+                }
+
+                // Only matches that start in the tail and end in the
+                // current region, everything else is found by
+                // scanning regions themselves
+                let offsets = find_all_signatures(&seam, sign)
+                    .take_while(|offset| *offset < tail.len())
+                    .filter(|offset| offset + sign.len() > tail.len());
+
+                for offset in offsets {
+                    if done[i] {
+                        break;
+                    }
+
+                    if report(i, seam_start + offset, &mut done) {
+                        return Ok(());
+                    }
+                }
+            }
+        }
 
-                Ok(())
+        for (i, sign) in signs.iter().enumerate() {
+            if done[i] {
+                continue;
             }
+
+            for offset in find_all_signatures(&buff, sign) {
+                if done[i] {
+                    break;
+                }
+
+                if report(i, region.from + offset, &mut done) {
+                    return Ok(());
+                }
+            }
+        }
+
+        // Region could be shorter than the overlap, so keeping
+        // previous tail too
+        tail.extend_from_slice(&buff[buff.len().saturating_sub(overlap)..]);
+        tail.drain(..tail.len().saturating_sub(overlap));
+        tail_end = region.from + buff.len();
+    }
+
+    Ok(())
+}
+
+fn first_match<'a, P: ProcessTraits, T: TryFrom<usize>>(
+    process: &P,
+    regions: impl Iterator<Item = &'a MemoryRegion>,
+    sign: &Signature,
+) -> Result<T, ProcessError> {
+    let mut found = None;
+
+    scan_regions(process, regions, &[sign], |_, addr| {
+        found = Some(addr);
+        ScanFlow::Stop
+    })?;
+
+    match found {
+        Some(addr) => addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError),
+        None => Err(ProcessError::SignatureNotFound(sign.to_string())),
+    }
+}
+
+/// Address of the first element and length of a CLR array `T[]`.
+///
+/// Array object starts with method table pointer followed by the
+/// length, which is padded to pointer size on 64 bit
+fn array_items<P: ProcessTraits>(
+    process: &P,
+    addr: usize,
+    width: PointerWidth,
+) -> Result<(usize, usize), ProcessError> {
+    let len = process.read_u32(addr + width.size())? as usize;
+
+    Ok((addr + TargetArch::from(width).array_data_offset(), len))
+}
+
+/// Reads `count` pointers of provided `width` widening them to
+/// `usize`
+fn read_ptrs<P: ProcessTraits>(
+    process: &P,
+    addr: usize,
+    count: usize,
+    width: PointerWidth,
+    buff: &mut Vec<usize>,
+) -> Result<(), ProcessError> {
+    buff.clear();
+
+    match width {
+        PointerWidth::X32 => {
+            let mut ptrs: Vec<u32> = Vec::new();
+            process.read_pod_array(addr, count, &mut ptrs)?;
+            buff.extend(ptrs.into_iter().map(|ptr| ptr as usize));
+        }
+        PointerWidth::X64 => {
+            let mut ptrs: Vec<u64> = Vec::new();
+            process.read_pod_array(addr, count, &mut ptrs)?;
+            buff.extend(ptrs.into_iter().map(|ptr| ptr as usize));
         }
+    }
+
+    Ok(())
+}
+
+/// Address of the first character and length of a C# string
+fn string_data<P: ProcessTraits>(
+    process: &P,
+    addr: usize,
+) -> Result<(usize, usize), ProcessError> {
+    // C# string structure: 4B/8B obj header, 4B str len, str itself
+    let len = process.read_u32(addr + process.arch().ptr_size())? as usize;
+
+    Ok((addr + process.arch().string_data_offset(), len))
+}
+
+/// Reads `len` UTF-16 code units at `addr` into `buff`, reusing its
+/// memory
+fn read_utf16<P: ProcessTraits>(
+    process: &P,
+    addr: usize,
+    len: usize,
+    buff: &mut Vec<u16>,
+) -> Result<(), ProcessError> {
+    buff.clear();
+    buff.resize(len, 0);
+
+    let byte_buff = unsafe {
+        std::slice::from_raw_parts_mut(
+            buff.as_mut_ptr() as *mut u8,
+            buff.len() * 2,
+        )
     };
+
+    process.read(addr, byte_buff.len(), byte_buff)
+}
+
+/// Reads `len` UTF-16 code units at `addr` passing them to `f` in
+/// chunks, so no heap allocations are made
+fn for_utf16_chunks<P: ProcessTraits>(
+    process: &P,
+    mut addr: usize,
+    len: usize,
+    mut f: impl FnMut(&[u16]),
+) -> Result<(), ProcessError> {
+    const CHUNK: usize = 512;
+
+    let mut chunk = [0u16; CHUNK];
+    let mut left = len;
+
+    while left > 0 {
+        let count = left.min(CHUNK);
+
+        let byte_buff = unsafe {
+            std::slice::from_raw_parts_mut(
+                chunk.as_mut_ptr() as *mut u8,
+                count * 2,
+            )
+        };
+
+        process.read(addr, byte_buff.len(), byte_buff)?;
+        f(&chunk[..count]);
+
+        addr += count * 2;
+        left -= count;
+    }
+
+    Ok(())
+}
+
+/// Address of the first element and `_size` of a CLR `List<T>`
+fn list_items<P: ProcessTraits>(
+    process: &P,
+    addr: usize,
+    width: PointerWidth,
+) -> Result<(usize, usize), ProcessError> {
+    // Reference fields go first, `_items` and on .NET Framework also
+    // `_syncRoot`, then `_size` and `_version`
+    let (items_offset, size_offset) = match width {
+        PointerWidth::X32 => (0x4, 0xC),
+        PointerWidth::X64 => (0x8, 0x10),
+    };
+
+    let size = process.read_u32(addr + size_offset)? as usize;
+
+    if size == 0 {
+        return Ok((0, 0));
+    }
+
+    let items = process.read_ptr(addr + items_offset, width)?;
+    let (data, capacity) = array_items(process, items, width)?;
+
+    if size > capacity {
+        return Err(ProcessError::InvalidCollectionSize { size, capacity });
+    }
+
+    Ok((data, size))
+}
+
+/// Address of the first entry and `_count` of a CLR `Dictionary`
+fn dictionary_entries<P: ProcessTraits>(
+    process: &P,
+    addr: usize,
+    width: PointerWidth,
+) -> Result<(usize, usize), ProcessError> {
+    // Reference fields go first: `_buckets`, `_entries`, `_comparer`,
+    // `_keys`, `_values` and on .NET Framework also `_syncRoot`. On
+    // .NET `_fastModMultiplier` goes before `_count` on 64 bit
+    let (entries_offset, count_offset) = match width {
+        PointerWidth::X32 => (0x8, 0x1C),
+        PointerWidth::X64 => (0x10, 0x38),
+    };
+
+    let count = process.read_u32(addr + count_offset)? as usize;
+
+    if count == 0 {
+        return Ok((0, 0));
+    }
+
+    let entries = process.read_ptr(addr + entries_offset, width)?;
+    let (data, capacity) = array_items(process, entries, width)?;
+
+    if count > capacity {
+        return Err(ProcessError::InvalidCollectionSize {
+            size: count,
+            capacity,
+        });
+    }
+
+    Ok((data, count))
+}
+
+fn convert_found<T: TryFrom<usize>>(
+    found: Vec<Option<usize>>,
+) -> Result<Vec<Option<T>>, ProcessError> {
+    found
+        .into_iter()
+        .map(|addr| {
+            addr.map(|addr| {
+                addr.try_into()
+                    .map_err(|_| ProcessError::AddressConvertError)
+            })
+            .transpose()
+        })
+        .collect()
 }
 
 pub struct Process {
@@ -87,6 +508,27 @@ pub struct Process {
     pub maps: Vec<MemoryRegion>,
 
     pub executable_dir: Option<PathBuf>,
+
+    /// Path of the executable image itself.
+    ///
+    /// On linux under wine it's taken from the command line with the
+    /// drive letter stripped, so it's a host path only for the `Z:`
+    /// drive
+    pub executable_path: Option<PathBuf>,
+
+    /// Architecture of the process.
+    ///
+    /// On windows it's known after [`ProcessTraits::find_process()`].
+    /// On linux it's detected from the executable image by
+    /// [`ProcessTraits::read_regions()`] and is the architecture of
+    /// the current process until then or if detection fails, so it
+    /// has to be set by hand in those cases, see
+    /// [`Process::arch_detected`]
+    pub arch: TargetArch,
+
+    /// Whether `arch` was detected from the process, `false` if it's
+    /// only assumed to be the architecture of the current process
+    pub arch_detected: bool,
 }
 
 pub trait ProcessTraits
@@ -126,10 +568,374 @@ where
     ///   Check out [`MemoryRegion`] for more info
     fn read_regions(self) -> Result<Self, ProcessError>;
 
+    /// Memory regions collected by [`ProcessTraits::read_regions()`]
+    fn regions(&self) -> &[MemoryRegion];
+
+    /// Architecture of the process, decides size of pointers and
+    /// layout of CLR objects read by string, array and `*_from_ptr()`
+    /// functions
+    fn arch(&self) -> TargetArch;
+
+    /// Reads whole memory region into `buff`
+    ///
+    /// Returns `Ok(false)` if region can't be read and should be
+    /// skipped while scanning.
+    ///
+    /// Notes:
+    /// * `buff` might be shorter than region after reading, if only
+    ///   part of the region is readable
+    fn read_region(
+        &self,
+        region: &MemoryRegion,
+        buff: &mut Vec<u8>,
+    ) -> Result<bool, ProcessError> {
+        buff.resize(region.size, 0);
+
+        match self.read(region.from, region.size, buff) {
+            Ok(()) => Ok(true),
+            Err(ProcessError::BadAddress(..)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Lists files mapped into process memory, consecutive mappings
+    /// of the same file are collapsed into one [`Module`].
+    ///
+    /// Built from [`ProcessTraits::regions()`], so PE images mapped
+    /// by wine are listed too.
+    fn modules(&self) -> Vec<Module> {
+        collect_modules(self.regions())
+    }
+
+    /// Finds module by its file name, ignoring ASCII case
+    fn module(&self, name: &str) -> Option<Module> {
+        self.modules()
+            .into_iter()
+            .find(|module| module.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Scans process memory and returns address of the first
+    /// signature match
+    ///
+    /// Scanned regions depend on the platform, see
+    /// [`RegionFilter::default()`]. For other regions use
+    /// [`ProcessTraits::read_signature_filtered()`]
+    ///
+    /// Notes:
+    /// * Matches that straddle two adjacent regions are found too
     fn read_signature<T: TryFrom<usize>>(
         &self,
         sign: &Signature,
-    ) -> Result<T, ProcessError>;
+    ) -> Result<T, ProcessError> {
+        self.read_signature_filtered(sign, &RegionFilter::default())
+    }
+
+    /// Same as [`ProcessTraits::read_signature()`] but scans only
+    /// regions matching `filter`
+    fn read_signature_filtered<T: TryFrom<usize>>(
+        &self,
+        sign: &Signature,
+        filter: &RegionFilter,
+    ) -> Result<T, ProcessError> {
+        let regions = self.regions().iter().filter(|r| filter.matches(r));
+
+        first_match(self, regions, sign)
+    }
+
+    /// Same as [`ProcessTraits::read_signature()`] but scans only
+    /// memory inside of `range`, regions crossing its bounds are
+    /// clipped. Useful when the signature is known to be in some heap
+    /// or image range.
+    ///
+    /// Notes:
+    /// * Every readable region is scanned, not only writable ones
+    fn read_signature_in<T: TryFrom<usize>>(
+        &self,
+        sign: &Signature,
+        range: Range<usize>,
+    ) -> Result<T, ProcessError> {
+        let regions: Vec<MemoryRegion> = self
+            .regions()
+            .iter()
+            .filter(|r| r.protection.read)
+            .filter_map(|r| r.clip(&range))
+            .collect();
+
+        first_match(self, regions.iter(), sign)
+    }
+
+    /// Same as [`ProcessTraits::read_signature()`] but scans only
+    /// regions mapped from `module`, for example `"clrjit.dll"`.
+    ///
+    /// Module is matched by the file name of the region path, ignoring
+    /// ASCII case. Returns [`ProcessError::ModuleNotFound`] if no
+    /// region belongs to the module.
+    ///
+    /// Notes:
+    /// * Every readable region is scanned, not only writable ones
+    fn read_signature_in_module<T: TryFrom<usize>>(
+        &self,
+        sign: &Signature,
+        module: &str,
+    ) -> Result<T, ProcessError> {
+        let regions: Vec<&MemoryRegion> = self
+            .regions()
+            .iter()
+            .filter(|r| r.protection.read && r.belongs_to(module))
+            .collect();
+
+        if regions.is_empty() {
+            return Err(ProcessError::ModuleNotFound(module.to_owned()));
+        }
+
+        first_match(self, regions.into_iter(), sign)
+    }
+
+    /// Scans process memory and returns addresses of every
+    /// signature match
+    ///
+    /// Notes:
+    /// * Returns empty `Vec` if nothing is found, not an error
+    fn read_signature_all<T: TryFrom<usize>>(
+        &self,
+        sign: &Signature,
+    ) -> Result<Vec<T>, ProcessError> {
+        self.read_signature_all_filtered(sign, &RegionFilter::default())
+    }
+
+    /// Same as [`ProcessTraits::read_signature_all()`] but scans only
+    /// regions matching `filter`
+    fn read_signature_all_filtered<T: TryFrom<usize>>(
+        &self,
+        sign: &Signature,
+        filter: &RegionFilter,
+    ) -> Result<Vec<T>, ProcessError> {
+        let mut found = Vec::new();
+        let regions = self.regions().iter().filter(|r| filter.matches(r));
+
+        scan_regions(self, regions, &[sign], |_, addr| {
+            found.push(addr);
+            ScanFlow::Continue
+        })?;
+
+        found
+            .into_iter()
+            .map(|addr| {
+                addr.try_into()
+                    .map_err(|_| ProcessError::AddressConvertError)
+            })
+            .collect()
+    }
+
+    /// Scans process memory for signature and decodes its captured
+    /// bytes (marked with `[ ]`) as an address
+    ///
+    /// ```ignore
+    /// // mov rax, [rip + ????????]
+    /// let sign = Signature::from_str("48 8B 05 [?? ?? ?? ??] 48 85 C0")?;
+    /// let addr: usize =
+    ///     process.read_signature_capture(&sign, CaptureKind::Relative)?;
+    /// ```
+    ///
+    /// Notes:
+    /// * [`CaptureKind::Relative`] displacement is relative to the end
+    ///   of the capture. If instruction has immediate after the
+    ///   displacement, add its size yourself
+    fn read_signature_capture<T: TryFrom<usize>>(
+        &self,
+        sign: &Signature,
+        kind: CaptureKind,
+    ) -> Result<T, ProcessError> {
+        if sign.capture().is_none() {
+            return Err(ProcessError::SignatureNoCapture(sign.to_string()));
+        }
+
+        let addr: usize = self.read_signature(sign)?;
+
+        self.read_capture(addr, sign, kind)
+    }
+
+    /// Decodes capture of `sign` that matched at `addr`, same as
+    /// [`ProcessTraits::read_signature_capture()`] but for already
+    /// known match, for example one found by
+    /// [`ProcessTraits::scan_signatures()`]
+    fn read_capture<T: TryFrom<usize>>(
+        &self,
+        addr: usize,
+        sign: &Signature,
+        kind: CaptureKind,
+    ) -> Result<T, ProcessError> {
+        let capture = sign.capture().ok_or_else(|| {
+            ProcessError::SignatureNoCapture(sign.to_string())
+        })?;
+
+        let capture_addr = addr + capture.offset;
+
+        let value = match (kind, capture.len) {
+            (CaptureKind::Absolute, 4) => {
+                self.read_ptr(capture_addr, PointerWidth::X32)?
+            }
+            (CaptureKind::Absolute, 8) => {
+                self.read_ptr(capture_addr, PointerWidth::X64)?
+            }
+            (CaptureKind::Relative, 1) => (capture_addr + 1)
+                .wrapping_add_signed(self.read_i8(capture_addr)? as isize),
+            (CaptureKind::Relative, 4) => (capture_addr + 4)
+                .wrapping_add_signed(self.read_i32(capture_addr)? as isize),
+            (_, len) => return Err(ProcessError::InvalidCaptureLength(len)),
+        };
+
+        value
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)
+    }
+
+    /// Scans process memory for several signatures at once, reading
+    /// every region only once.
+    ///
+    /// Returns address of the first match for every signature, in the
+    /// same order as `signs`. Signatures that are not found are `None`.
+    ///
+    /// Notes:
+    /// * Scans same regions as [`ProcessTraits::read_signature()`]
+    /// * With `parallel` feature regions can be scanned by multiple
+    ///   threads, see [`ProcessTraits::par_scan_signatures()`]
+    fn scan_signatures<T: TryFrom<usize>>(
+        &self,
+        signs: &[&Signature],
+    ) -> Result<Vec<Option<T>>, ProcessError> {
+        self.scan_signatures_filtered(signs, &RegionFilter::default())
+    }
+
+    /// Same as [`ProcessTraits::scan_signatures()`] but scans only
+    /// regions matching `filter`
+    fn scan_signatures_filtered<T: TryFrom<usize>>(
+        &self,
+        signs: &[&Signature],
+        filter: &RegionFilter,
+    ) -> Result<Vec<Option<T>>, ProcessError> {
+        let mut found = vec![None; signs.len()];
+        let regions = self.regions().iter().filter(|r| filter.matches(r));
+
+        scan_regions(self, regions, signs, |i, addr| {
+            found[i] = Some(addr);
+            ScanFlow::Done
+        })?;
+
+        convert_found(found)
+    }
+
+    /// Same as [`ProcessTraits::scan_signatures_filtered()`] but regions
+    /// are scanned in parallel using `rayon` thread pool
+    #[cfg(feature = "parallel")]
+    fn par_scan_signatures<T: TryFrom<usize>>(
+        &self,
+        signs: &[&Signature],
+        filter: &RegionFilter,
+    ) -> Result<Vec<Option<T>>, ProcessError>
+    where
+        Self: Sync,
+    {
+        use rayon::prelude::*;
+
+        if signs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let overlap = signs.iter().map(|s| s.len()).max().unwrap_or(0);
+        let overlap = overlap.saturating_sub(1);
+
+        let regions: Vec<&MemoryRegion> = self
+            .regions()
+            .iter()
+            .filter(|r| filter.matches(r))
+            .collect();
+
+        let results = regions
+            .par_iter()
+            .enumerate()
+            .map(|(i, region)| {
+                // Matches straddling the boundary with previous regions
+                // are found by also scanning their last bytes, going
+                // back through as many adjacent regions as needed.
+                // Matches that are fully inside of them are found
+                // twice, but only the lowest address is kept anyway.
+                let mut from = region.from;
+
+                for prev in regions[..i].iter().rev() {
+                    if region.from - from >= overlap
+                        || prev.from + prev.size != from
+                    {
+                        break;
+                    }
+
+                    from = prev.from;
+                }
+
+                let size = overlap.min(region.from - from);
+                let tail = (size > 0).then(|| MemoryRegion {
+                    from: region.from - size,
+                    size,
+                    ..Default::default()
+                });
+
+                let mut found = vec![None; signs.len()];
+
+                scan_regions(
+                    self,
+                    tail.iter().chain([*region]),
+                    signs,
+                    |i, addr| {
+                        found[i] = Some(addr);
+                        ScanFlow::Done
+                    },
+                )?;
+
+                Ok(found)
+            })
+            .collect::<Result<Vec<_>, ProcessError>>()?;
+
+        let mut found = vec![None; signs.len()];
+
+        for region_found in results {
+            for (first, addr) in found.iter_mut().zip(region_found) {
+                *first = match (*first, addr) {
+                    (Some(a), Some(b)) => Some(std::cmp::min(a, b)),
+                    (a, b) => a.or(b),
+                };
+            }
+        }
+
+        convert_found(found)
+    }
+
+    /// Same as [`ProcessTraits::read_signature()`] but makes sure
+    /// that signature matches only once
+    ///
+    /// Will throw a [`ProcessError::SignatureAmbiguous`] error
+    /// if there is more than one match
+    fn read_signature_unique<T: TryFrom<usize>>(
+        &self,
+        sign: &Signature,
+    ) -> Result<T, ProcessError> {
+        self.read_signature_unique_filtered(sign, &RegionFilter::default())
+    }
+
+    /// Same as [`ProcessTraits::read_signature_unique()`] but scans only
+    /// regions matching `filter`
+    fn read_signature_unique_filtered<T: TryFrom<usize>>(
+        &self,
+        sign: &Signature,
+        filter: &RegionFilter,
+    ) -> Result<T, ProcessError> {
+        let mut found = self.read_signature_all_filtered(sign, filter)?;
+
+        match found.len() {
+            0 => Err(ProcessError::SignatureNotFound(sign.to_string())),
+            1 => Ok(found.remove(0)),
+            count => Err(ProcessError::SignatureAmbiguous(count)),
+        }
+    }
 
     fn read<T: TryInto<usize>>(
         &self,
@@ -138,6 +944,389 @@ where
         buff: &mut [u8],
     ) -> Result<(), ProcessError>;
 
+    /// Executes all reads queued in the `batch`
+    ///
+    /// Returns result for every entry in the same order entries
+    /// were pushed, so one bad address doesn't fail the whole batch.
+    /// Outer error is returned only if process itself can't be read
+    /// anymore.
+    ///
+    /// Notes:
+    /// * Default implementation just calls [`ProcessTraits::read()`]
+    ///   for every entry
+    fn read_batch(
+        &self,
+        batch: &mut ReadBatch<'_>,
+    ) -> Result<Vec<Result<(), ProcessError>>, ProcessError> {
+        Ok(batch
+            .entries_mut()
+            .iter_mut()
+            .map(|entry| self.read(entry.addr, entry.buff.len(), entry.buff))
+            .collect())
+    }
+
+    /// Writes whole `buff` into process memory starting at `addr`
+    ///
+    /// Notes:
+    /// * On linux if page is not writable (for example code pages)
+    ///   it's going to fallback to writing through `/proc/<pid>/mem`
+    /// * On windows process is opened with write rights on every call,
+    ///   handle from [`ProcessTraits::find_process()`] is read-only
+    fn write<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        buff: &[u8],
+    ) -> Result<(), ProcessError>;
+
+    /// Reads a pointer of provided `width` and converts it to `usize`
+    fn read_ptr<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        width: PointerWidth,
+    ) -> Result<usize, ProcessError> {
+        match width {
+            PointerWidth::X32 => Ok(self.read_u32(addr)? as usize),
+            PointerWidth::X64 => self
+                .read_u64(addr)?
+                .try_into()
+                .map_err(|_| ProcessError::AddressConvertError),
+        }
+    }
+
+    /// Resolves a [`PointerChain`] into the final pointer
+    ///
+    /// If any of the hops fails to read, error is wrapped into
+    /// [`ProcessError::BrokenPointerChain`] containing index of the failed
+    /// hop and address that was read.
+    fn resolve_chain(
+        &self,
+        chain: &PointerChain,
+    ) -> Result<usize, ProcessError> {
+        let mut addr = match &chain.base {
+            ChainBase::Address(addr) => *addr,
+            ChainBase::Signature(sign) => self.read_signature(sign)?,
+        };
+
+        for (hop, offset) in chain.offsets.iter().enumerate() {
+            let hop_addr = addr.wrapping_add_signed(*offset);
+
+            addr = self.read_ptr(hop_addr, chain.width).map_err(|e| {
+                ProcessError::BrokenPointerChain {
+                    hop,
+                    addr: hop_addr,
+                    source: Box::new(e),
+                }
+            })?;
+        }
+
+        Ok(addr)
+    }
+
+    /// Reads a whole [`Pod`] value in one read
+    ///
+    /// Useful for reading `#[repr(C)]` structures instead of reading
+    /// every field separately
+    fn read_pod<P: Pod, T: TryInto<usize>>(
+        &self,
+        addr: T,
+    ) -> Result<P, ProcessError> {
+        let mut value = std::mem::MaybeUninit::<P>::zeroed();
+
+        let byte_buff = unsafe {
+            std::slice::from_raw_parts_mut(
+                value.as_mut_ptr() as *mut u8,
+                std::mem::size_of::<P>(),
+            )
+        };
+
+        self.read(addr, byte_buff.len(), byte_buff)?;
+
+        // Safety: every bit pattern is valid for `Pod` types
+        Ok(unsafe { value.assume_init() })
+    }
+
+    /// Reads `count` consecutive [`Pod`] values into `buff`
+    ///
+    /// Notes:
+    /// * `buff` is cleared before reading, existing allocation is reused
+    fn read_pod_array<P: Pod, T: TryInto<usize>>(
+        &self,
+        addr: T,
+        count: usize,
+        buff: &mut Vec<P>,
+    ) -> Result<(), ProcessError> {
+        buff.clear();
+
+        // Safety: zeroed bytes are valid for `Pod` types
+        buff.resize(count, unsafe { std::mem::zeroed() });
+
+        let byte_buff = unsafe {
+            std::slice::from_raw_parts_mut(
+                buff.as_mut_ptr() as *mut u8,
+                count * std::mem::size_of::<P>(),
+            )
+        };
+
+        self.read(addr, byte_buff.len(), byte_buff)
+    }
+
+    /// Reads elements of a CLR `List<T>` object located at `addr`,
+    /// only first `_size` elements of the backing array are read.
+    ///
+    /// Object layout differs between 32 bit .NET Framework (osu!
+    /// stable) and 64 bit .NET (osu! lazer), so it's selected by
+    /// [`ProcessTraits::arch()`]
+    ///
+    /// Returns [`ProcessError::InvalidCollectionSize`] if `_size` is
+    /// larger than the backing array, which usually means `addr`
+    /// isn't a list
+    fn read_list<P: Pod, T: TryInto<usize>>(
+        &self,
+        addr: T,
+        buff: &mut Vec<P>,
+    ) -> Result<(), ProcessError> {
+        self.read_list_with_width(addr, self.arch().width(), buff)
+    }
+
+    /// Same as [`ProcessTraits::read_list()`] but with the layout of
+    /// provided `width` instead of [`ProcessTraits::arch()`]
+    fn read_list_with_width<P: Pod, T: TryInto<usize>>(
+        &self,
+        addr: T,
+        width: PointerWidth,
+        buff: &mut Vec<P>,
+    ) -> Result<(), ProcessError> {
+        let addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        let (items, size) = list_items(self, addr, width)?;
+
+        self.read_pod_array(items, size, buff)
+    }
+
+    /// Same as [`ProcessTraits::read_list()`] but for lists of
+    /// references, like `List<string>` or `List<Score>`. Pointers are
+    /// widened to `usize`
+    fn read_list_of_ptrs<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        buff: &mut Vec<usize>,
+    ) -> Result<(), ProcessError> {
+        self.read_list_of_ptrs_with_width(addr, self.arch().width(), buff)
+    }
+
+    /// Same as [`ProcessTraits::read_list_of_ptrs()`] but with the
+    /// layout and pointers of provided `width` instead of
+    /// [`ProcessTraits::arch()`]
+    fn read_list_of_ptrs_with_width<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        width: PointerWidth,
+        buff: &mut Vec<usize>,
+    ) -> Result<(), ProcessError> {
+        let addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        let (items, size) = list_items(self, addr, width)?;
+
+        read_ptrs(self, items, size, width, buff)
+    }
+
+    /// Reads elements of a CLR array `T[]` located at `addr`.
+    ///
+    /// Length is stored after the method table pointer and is padded
+    /// to pointer size, so elements start at `+0x8` for 32 bit
+    /// processes (osu! stable) and at `+0x10` for 64 bit ones (osu!
+    /// lazer), see [`ProcessTraits::arch()`]
+    ///
+    /// Length is read from the target memory as is, use
+    /// [`ProcessTraits::read_array_with_limit()`] if `addr` may not
+    /// point to an array
+    fn read_array<P: Pod, T: TryInto<usize>>(
+        &self,
+        addr: T,
+        buff: &mut Vec<P>,
+    ) -> Result<(), ProcessError> {
+        self.read_array_with_limit(addr, usize::MAX, buff)
+    }
+
+    /// Same as [`ProcessTraits::read_array()`]
+    ///
+    /// The only difference is that function will throw a
+    /// [`ProcessError::ArrayTooLarge`] error if array length is over
+    /// a provided limit
+    fn read_array_with_limit<P: Pod, T: TryInto<usize>>(
+        &self,
+        addr: T,
+        limit: usize,
+        buff: &mut Vec<P>,
+    ) -> Result<(), ProcessError> {
+        let addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        let (items, len) = array_items(self, addr, self.arch().width())?;
+
+        if len > limit {
+            return Err(ProcessError::ArrayTooLarge(len));
+        }
+
+        self.read_pod_array(items, len, buff)
+    }
+
+    /// Same as [`ProcessTraits::read_array()`] but for arrays of
+    /// references, like `string[]`. Addresses of referenced objects
+    /// are widened to `usize`
+    fn read_ptr_array<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        buff: &mut Vec<usize>,
+    ) -> Result<(), ProcessError> {
+        self.read_ptr_array_with_limit(addr, usize::MAX, buff)
+    }
+
+    /// Same as [`ProcessTraits::read_ptr_array()`]
+    ///
+    /// The only difference is that function will throw a
+    /// [`ProcessError::ArrayTooLarge`] error if array length is over
+    /// a provided limit
+    fn read_ptr_array_with_limit<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        limit: usize,
+        buff: &mut Vec<usize>,
+    ) -> Result<(), ProcessError> {
+        let addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        let width = self.arch().width();
+        let (items, len) = array_items(self, addr, width)?;
+
+        if len > limit {
+            return Err(ProcessError::ArrayTooLarge(len));
+        }
+
+        read_ptrs(self, items, len, width, buff)
+    }
+
+    /// Reads key-value pairs of a CLR `Dictionary<TKey, TValue>`
+    /// located at `addr` into `buff`, skipping removed entries.
+    ///
+    /// `read_key` and `read_value` receive addresses of the `key` and
+    /// `value` fields of every entry, described by `entry`
+    ///
+    /// # Panics
+    ///
+    /// If `hashCode` or `next` field of `entry` doesn't fit into its
+    /// size
+    ///
+    /// Dictionary layout is selected by [`ProcessTraits::arch()`]
+    ///
+    /// ```no_run
+    /// use rosu_mem::process::{DictionaryEntry, Process, ProcessTraits};
+    ///
+    /// # fn main() -> Result<(), rosu_mem::error::ProcessError> {
+    /// # let process = Process::initialize("osu!.exe", &[])?;
+    /// # let config = 0x1000;
+    /// let width = process.arch().width();
+    /// let mut bindables = Vec::new();
+    ///
+    /// process.read_dictionary(
+    ///     config,
+    ///     DictionaryEntry::references(width),
+    ///     |p, addr| p.read_string_from_ptr(addr),
+    ///     |p, addr| p.read_ptr(addr, width),
+    ///     &mut bindables,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn read_dictionary<K, V, T, FK, FV>(
+        &self,
+        addr: T,
+        entry: DictionaryEntry,
+        read_key: FK,
+        read_value: FV,
+        buff: &mut Vec<(K, V)>,
+    ) -> Result<(), ProcessError>
+    where
+        T: TryInto<usize>,
+        FK: FnMut(&Self, usize) -> Result<K, ProcessError>,
+        FV: FnMut(&Self, usize) -> Result<V, ProcessError>,
+    {
+        self.read_dictionary_with_width(
+            addr,
+            self.arch().width(),
+            entry,
+            read_key,
+            read_value,
+            buff,
+        )
+    }
+
+    /// Same as [`ProcessTraits::read_dictionary()`] but with the layout
+    /// of provided `width` instead of [`ProcessTraits::arch()`]
+    fn read_dictionary_with_width<K, V, T, FK, FV>(
+        &self,
+        addr: T,
+        width: PointerWidth,
+        entry: DictionaryEntry,
+        mut read_key: FK,
+        mut read_value: FV,
+        buff: &mut Vec<(K, V)>,
+    ) -> Result<(), ProcessError>
+    where
+        T: TryInto<usize>,
+        FK: FnMut(&Self, usize) -> Result<K, ProcessError>,
+        FV: FnMut(&Self, usize) -> Result<V, ProcessError>,
+    {
+        let addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        let (entries, count) = dictionary_entries(self, addr, width)?;
+
+        buff.clear();
+
+        if count == 0 {
+            return Ok(());
+        }
+
+        let mut bytes = vec![0u8; count * entry.size];
+        self.read(entries, bytes.len(), &mut bytes)?;
+
+        let field = |raw: &[u8], offset: usize| {
+            i32::from_le_bytes(raw[offset..offset + 4].try_into().unwrap())
+        };
+
+        for (i, raw) in bytes.chunks_exact(entry.size).enumerate() {
+            // Removed entries are chained into the free list. On .NET
+            // Framework they are marked by negative `hashCode`, while
+            // on .NET `next` is encoded as `-3 - next_free`
+            let removed = if entry.framework {
+                field(raw, entry.hash_code) < 0
+            } else {
+                field(raw, entry.next) < -1
+            };
+
+            if removed {
+                continue;
+            }
+
+            let entry_addr = entries + i * entry.size;
+
+            buff.push((
+                read_key(self, entry_addr + entry.key)?,
+                read_value(self, entry_addr + entry.value)?,
+            ));
+        }
+
+        Ok(())
+    }
+
     fn read_uleb128<T: TryInto<usize>>(
         &self,
         addr: T,
@@ -166,23 +1355,56 @@ where
         Ok(value)
     }
 
+    /// Reads a NUL terminated string, like names inside of PE and ELF
+    /// images. Invalid UTF-8 is replaced with `U+FFFD`.
+    ///
+    /// Returns [`ProcessError::StringTooLarge`] error if there is no
+    /// NUL in the first `limit` bytes
+    fn read_c_string<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        limit: usize,
+    ) -> Result<String, ProcessError> {
+        // Chunks are aligned, so they never cross a page boundary
+        const CHUNK: usize = 64;
+
+        let mut addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        let mut bytes = Vec::new();
+        let mut chunk = [0u8; CHUNK];
+
+        while bytes.len() <= limit {
+            let len = CHUNK - addr % CHUNK;
+            self.read(addr, len, &mut chunk[..len])?;
+
+            let end = memchr::memchr(0, &chunk[..len]);
+            bytes.extend_from_slice(&chunk[..end.unwrap_or(len)]);
+
+            if end.is_some() && bytes.len() <= limit {
+                return Ok(String::from_utf8_lossy(&bytes).into_owned());
+            }
+
+            addr += len;
+        }
+
+        Err(ProcessError::StringTooLarge)
+    }
+
     /// Same behaviour as [`ProcessTraits::read_string_from_ptr()`]
     ///
     /// The only diffrence is that function will throw
     /// a [`ProcessError::StringTooLarge`] error if readed string length
     /// is over a provided limit
     ///
-    /// Notes:
-    /// * `*_from_ptr()` functions usually will result in additional
-    ///   heap allocation, due to generic behaviour. If you need to avoid
-    ///   heap allocations at all costs, read pointer manually and then pass
-    ///   address to the [`ProcessTraits::read_string()`] function
+    /// Size of the pointer is decided by [`ProcessTraits::arch()`]
     fn read_string_with_limit_from_ptr<T: TryInto<usize>>(
         &self,
         addr: T,
         limit: usize,
     ) -> Result<String, ProcessError> {
-        let addr = read_generic!(T, self, addr);
+        let addr = self.read_ptr(addr, self.arch().width())?;
 
         self.read_string_with_limit(addr, limit)
     }
@@ -201,7 +1423,7 @@ where
             .try_into()
             .map_err(|_| ProcessError::AddressConvertError)?;
 
-        addr += std::mem::size_of::<T>();
+        addr += self.arch().ptr_size(); // Skipping method table pointer
 
         let len = self.read_u32(addr)? as usize; // Reading 4B str len
 
@@ -225,20 +1447,135 @@ where
         Ok(String::from_utf16_lossy(&buff))
     }
 
+    /// Same as [`ProcessTraits::read_string()`] but reuses memory of
+    /// `buff` instead of allocating a new `String`, which is useful
+    /// when the same string is read over and over again
+    fn read_string_into<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        buff: &mut String,
+    ) -> Result<(), ProcessError> {
+        let addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        let (data, len) = string_data(self, addr)?;
+
+        buff.clear();
+        buff.reserve(len);
+
+        // High surrogate at the end of a chunk, its pair is at the
+        // start of the next one
+        let mut pending: Option<u16> = None;
+
+        for_utf16_chunks(self, data, len, |mut chunk| {
+            let units = pending.take();
+
+            if let Some((&last, rest)) = chunk.split_last() {
+                if (0xD800..0xDC00).contains(&last) {
+                    pending = Some(last);
+                    chunk = rest;
+                }
+            }
+
+            buff.extend(
+                char::decode_utf16(
+                    units.into_iter().chain(chunk.iter().copied()),
+                )
+                .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER)),
+            );
+        })?;
+
+        if pending.is_some() {
+            buff.push(char::REPLACEMENT_CHARACTER);
+        }
+
+        Ok(())
+    }
+
+    /// Reads raw UTF-16 contents of a C# string into `buff`, reusing
+    /// its memory. No decoding is done, so unpaired surrogates are
+    /// kept as is
+    fn read_utf16_into<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        buff: &mut Vec<u16>,
+    ) -> Result<(), ProcessError> {
+        let addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        let (data, len) = string_data(self, addr)?;
+
+        read_utf16(self, data, len, buff)
+    }
+
+    /// Checks if C# string at `addr` differs from the one `last` was
+    /// taken from. Length is compared first, so strings of a different
+    /// length are reported without comparing their contents. Strings
+    /// of the same length are read into buffers kept by `last` and
+    /// compared, so it saves decoding and allocations, not reads.
+    /// Changed string can be decoded with
+    /// [`StringFingerprint::decode_into()`] without reading it again.
+    ///
+    /// `last` is updated to match the current string
+    ///
+    /// ```no_run
+    /// use rosu_mem::process::{Process, ProcessTraits, StringFingerprint};
+    ///
+    /// # fn main() -> Result<(), rosu_mem::error::ProcessError> {
+    /// # let process = Process::initialize("osu!.exe", &[])?;
+    /// # let title_addr = 0x1000;
+    /// let mut fingerprint = StringFingerprint::default();
+    /// let mut title = String::new();
+    ///
+    /// loop {
+    ///     if process.string_changed(title_addr, &mut fingerprint)? {
+    ///         fingerprint.decode_into(&mut title);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    fn string_changed<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        last: &mut StringFingerprint,
+    ) -> Result<bool, ProcessError> {
+        let addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        let (data, len) = string_data(self, addr)?;
+
+        if !last.read || len != last.units.len() {
+            last.read = false;
+            read_utf16(self, data, len, &mut last.units)?;
+            last.read = true;
+
+            return Ok(true);
+        }
+
+        read_utf16(self, data, len, &mut last.scratch)?;
+
+        if last.scratch == last.units {
+            return Ok(false);
+        }
+
+        std::mem::swap(&mut last.units, &mut last.scratch);
+
+        Ok(true)
+    }
+
     /// Reads a C# string based on C# string structure
     /// Assumes passed `addr` is a pointer, so it's gonna make
     /// additional pointer read.
     ///
-    /// Notes:
-    /// * `*_from_ptr()` functions usually will result in additional
-    ///   heap allocation, due to generic behaviour. If you need to avoid
-    ///   heap allocations at all costs, read pointer manually and then pass
-    ///   address to the [`ProcessTraits::read_string()`] function
+    /// Size of the pointer is decided by [`ProcessTraits::arch()`]
     fn read_string_from_ptr<T: TryInto<usize>>(
         &self,
         addr: T,
     ) -> Result<String, ProcessError> {
-        let addr = read_generic!(T, self, addr);
+        let addr = self.read_ptr(addr, self.arch().width())?;
 
         self.read_string(addr)
     }
@@ -252,13 +1589,23 @@ where
     fn read_string<T: TryInto<usize>>(
         &self,
         addr: T,
+    ) -> Result<String, ProcessError> {
+        self.read_string_with_width(addr, self.arch().width())
+    }
+
+    /// Same as [`ProcessTraits::read_string()`] but with the object
+    /// header of provided `width` instead of [`ProcessTraits::arch()`]
+    fn read_string_with_width<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        width: PointerWidth,
     ) -> Result<String, ProcessError> {
         let mut addr: usize = addr
             .try_into()
             .map_err(|_| ProcessError::AddressConvertError)?;
 
         // C# string structure: 4B/8B obj header, 4B str len, str itself
-        addr += std::mem::size_of::<T>(); // Skipping 4B/8B obj header depending on endiness
+        addr += width.size(); // Skipping 4B/8B obj header
 
         let len = self.read_u32(addr)? as usize; // Reading 4B str len
         addr += 0x4; // Since we read length skipping it too
@@ -292,6 +1639,21 @@ where
     prim_read_impl!(f32);
     prim_read_impl!(f64);
 
+    prim_write_impl!(i8);
+    prim_write_impl!(i16);
+    prim_write_impl!(i32);
+    prim_write_impl!(i64);
+    prim_write_impl!(i128);
+
+    prim_write_impl!(u8);
+    prim_write_impl!(u16);
+    prim_write_impl!(u32);
+    prim_write_impl!(u64);
+    prim_write_impl!(u128);
+
+    prim_write_impl!(f32);
+    prim_write_impl!(f64);
+
     prim_read_array_impl!(i8);
     prim_read_array_impl!(i16);
     prim_read_array_impl!(i32);
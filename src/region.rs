@@ -0,0 +1,144 @@
+use crate::process::MemoryRegion;
+
+/// Memory protection of a [`MemoryRegion`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Protection {
+    pub read: bool,
+    pub write: bool,
+    pub execute: bool,
+}
+
+impl Protection {
+    pub const fn new(read: bool, write: bool, execute: bool) -> Self {
+        Self {
+            read,
+            write,
+            execute,
+        }
+    }
+}
+
+/// What is the memory region used for
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RegionKind {
+    /// Private memory that isn't backed by any file, that's where
+    /// managed heap of C# applications usually lives
+    #[default]
+    Anonymous,
+    /// Process heap (`[heap]` on linux)
+    Heap,
+    /// Thread stack (`[stack]` on linux)
+    Stack,
+    /// Mapped from a file, executable images and shared libraries
+    /// included
+    File,
+    /// Something else, for example `[vdso]` or `[vvar]` on linux
+    Other,
+}
+
+/// Describes which memory regions should be scanned
+///
+/// Every constraint that is set must be satisfied for region to match.
+///
+/// ```
+/// use rosu_mem::region::{RegionFilter, RegionKind};
+///
+/// // Only executable code of osu!.exe image
+/// let filter = RegionFilter::any()
+///     .executable(true)
+///     .kind(RegionKind::File)
+///     .path_contains("osu!.exe");
+///
+/// // Only anonymous memory, usually managed heap
+/// let filter = RegionFilter::default().kind(RegionKind::Anonymous);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RegionFilter {
+    pub readable: Option<bool>,
+    pub writable: Option<bool>,
+    pub executable: Option<bool>,
+    pub kinds: Vec<RegionKind>,
+    pub path_contains: Option<String>,
+}
+
+impl Default for RegionFilter {
+    /// Regions scanned by
+    /// [`crate::process::ProcessTraits::read_signature()`]: every
+    /// readable region on windows, including executable code of the
+    /// images, and only readable and writable ones on linux
+    fn default() -> Self {
+        if cfg!(target_os = "windows") {
+            Self::any().readable(true)
+        } else {
+            Self::any().readable(true).writable(true)
+        }
+    }
+}
+
+impl RegionFilter {
+    /// Filter that matches every region
+    pub const fn any() -> Self {
+        Self {
+            readable: None,
+            writable: None,
+            executable: None,
+            kinds: Vec::new(),
+            path_contains: None,
+        }
+    }
+
+    pub fn readable(mut self, value: bool) -> Self {
+        self.readable = Some(value);
+        self
+    }
+
+    pub fn writable(mut self, value: bool) -> Self {
+        self.writable = Some(value);
+        self
+    }
+
+    pub fn executable(mut self, value: bool) -> Self {
+        self.executable = Some(value);
+        self
+    }
+
+    /// Allows region kind, can be called multiple times to allow
+    /// several kinds. If never called every kind is allowed
+    pub fn kind(mut self, kind: RegionKind) -> Self {
+        self.kinds.push(kind);
+        self
+    }
+
+    /// Only regions which backing file path contains `value`
+    pub fn path_contains(mut self, value: impl Into<String>) -> Self {
+        self.path_contains = Some(value.into());
+        self
+    }
+
+    pub fn matches(&self, region: &MemoryRegion) -> bool {
+        let protection = &region.protection;
+
+        let flag = |filter: Option<bool>, value: bool| {
+            filter.is_none_or(|filter| filter == value)
+        };
+
+        if !flag(self.readable, protection.read)
+            || !flag(self.writable, protection.write)
+            || !flag(self.executable, protection.execute)
+        {
+            return false;
+        }
+
+        if !self.kinds.is_empty() && !self.kinds.contains(&region.kind) {
+            return false;
+        }
+
+        match &self.path_contains {
+            Some(value) => region
+                .path
+                .as_ref()
+                .is_some_and(|path| path.to_string_lossy().contains(value)),
+            None => true,
+        }
+    }
+}
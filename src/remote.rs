@@ -0,0 +1,32 @@
+use crate::{error::ProcessError, process::ProcessTraits};
+
+#[cfg(feature = "derive")]
+pub use rosu_mem_derive::RemoteStruct;
+
+/// Structure that can be read from the process memory field by field
+///
+/// Usually it's not implemented manually, but derived with
+/// `#[derive(RemoteStruct)]` (requires `derive` feature)
+///
+/// ```ignore
+/// use rosu_mem::remote::RemoteStruct;
+///
+/// #[derive(RemoteStruct)]
+/// struct Beatmap {
+///     #[offset(0x2C)]
+///     ar: f32,
+///     #[offset(0x80)]
+///     #[csharp_string]
+///     artist: String,
+///     #[offset(0x38)]
+///     #[ptr]
+///     hit_object_times: Vec<i32>,
+/// }
+/// ```
+pub trait RemoteStruct: Sized {
+    /// Reads a structure located at `addr`
+    fn read_from<P: ProcessTraits>(
+        process: &P,
+        addr: usize,
+    ) -> Result<Self, ProcessError>;
+}
@@ -1,6 +1,8 @@
 use crate::error::ParseSignatureError;
 
+use memchr::memmem::Finder;
 use std::{
+    borrow::Cow,
     fmt::{Display, Formatter, Result as FmtResult},
     str::FromStr,
 };
@@ -8,16 +10,52 @@ use std::{
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum SignatureByte {
     Byte(u8),
+    /// Only bits set in `mask` are compared, e.g. `4?` is
+    /// `Masked { value: 0x40, mask: 0xF0 }`
+    Masked {
+        value: u8,
+        mask: u8,
+    },
     Any,
 }
 
+impl SignatureByte {
+    /// Creates a masked byte, falling back to [`SignatureByte::Byte`]
+    /// or [`SignatureByte::Any`] if mask is full or empty
+    pub const fn masked(value: u8, mask: u8) -> Self {
+        match mask {
+            0x00 => Self::Any,
+            0xFF => Self::Byte(value),
+            _ => Self::Masked {
+                value: value & mask,
+                mask,
+            },
+        }
+    }
+}
+
 impl FromStr for SignatureByte {
-    type Err = std::num::ParseIntError;
+    type Err = ParseSignatureError;
+
+    /// Accepts a single byte in any form [`Signature`] does: `AB`,
+    /// `??`, `?`, `*`, half-wildcards like `4?` / `?F` and explicit
+    /// masks like `40&F8` (value & mask)
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let input = value.as_bytes();
+
+        let invalid =
+            |position, len| RawError::Token { position, len }.into_error(value);
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "??" => Ok(Self::Any),
-            _ => u8::from_str_radix(s, 16).map(Self::Byte),
+        match next_token(input, 0) {
+            Ok(Some((Token::Byte(byte), next))) => {
+                match next_token(input, next) {
+                    Ok(None) => Ok(byte),
+                    _ => Err(invalid(0, input.len())),
+                }
+            }
+            Ok(Some(_)) => Err(invalid(0, input.len())),
+            Ok(None) => Err(ParseSignatureError::Empty),
+            Err((position, len)) => Err(invalid(position, len)),
         }
     }
 }
@@ -27,6 +65,9 @@ impl PartialEq<u8> for SignatureByte {
         match self {
             SignatureByte::Any => true,
             SignatureByte::Byte(b) => b == other,
+            SignatureByte::Masked { value, mask } => {
+                (other ^ value) & mask == 0
+            }
         }
     }
 }
@@ -35,50 +76,518 @@ impl Display for SignatureByte {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
             SignatureByte::Byte(byte) => write!(f, "{byte:02X}"),
+            SignatureByte::Masked { value, mask: 0xF0 } => {
+                write!(f, "{:X}?", value >> 4)
+            }
+            SignatureByte::Masked { value, mask: 0x0F } => {
+                write!(f, "?{:X}", value & 0x0F)
+            }
+            SignatureByte::Masked { value, mask } => {
+                write!(f, "{:02X}&{mask:02X}", value & mask)
+            }
             SignatureByte::Any => f.write_str("??"),
         }
     }
 }
 
+/// Part of the signature marked with `[` and `]`, for example
+/// `48 8B 05 [?? ?? ?? ??] 48 85 C0`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Capture {
+    /// Offset of the first captured byte from the signature start
+    pub offset: usize,
+    /// Amount of captured bytes
+    pub len: usize,
+}
+
+/// How captured bytes should be decoded,
+/// see [`crate::process::ProcessTraits::read_signature_capture()`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "database",
+    derive(serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum CaptureKind {
+    /// Captured bytes are an absolute address (4 or 8 bytes)
+    Absolute,
+    /// Captured bytes are a signed displacement (1 or 4 bytes) relative
+    /// to the end of the capture, like RIP-relative operands and
+    /// relative jumps/calls
+    Relative,
+}
+
 #[derive(Debug)]
 pub struct Signature {
-    bytes: Box<[SignatureByte]>,
+    /// Borrowed for signatures created with
+    /// [`signature!`](crate::signature!)
+    bytes: Cow<'static, [SignatureByte]>,
+    capture: Option<Capture>,
+}
+
+/// Single element of a signature string
+enum Token {
+    Byte(SignatureByte),
+    CaptureStart,
+    CaptureEnd,
+}
+
+const fn is_wildcard(c: u8) -> bool {
+    matches!(c, b'?' | b'*')
+}
+
+const fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Parses two characters of a byte: `AB`, `??`, `4?` or `?F`
+const fn parse_pair(high: u8, low: u8) -> Option<SignatureByte> {
+    match (is_wildcard(high), is_wildcard(low)) {
+        (true, true) => Some(SignatureByte::Any),
+        (true, false) => match hex_digit(low) {
+            Some(low) => Some(SignatureByte::masked(low, 0x0F)),
+            None => None,
+        },
+        (false, true) => match hex_digit(high) {
+            Some(high) => Some(SignatureByte::masked(high << 4, 0xF0)),
+            None => None,
+        },
+        (false, false) => match (hex_digit(high), hex_digit(low)) {
+            (Some(high), Some(low)) => {
+                Some(SignatureByte::Byte(high << 4 | low))
+            }
+            _ => None,
+        },
+    }
+}
+
+/// Parses next token of `input` starting at `pos`, returns it together
+/// with position right after it, or `(position, len)` of invalid word.
+///
+/// Words are split into bytes two characters at a time, so `8B05????`
+/// is the same as `8B 05 ?? ??`. Lone `?` or `*` is a whole wildcard
+/// byte, like in IDA, x64dbg or Cheat Engine patterns.
+const fn next_token(
+    input: &[u8],
+    mut pos: usize,
+) -> Result<Option<(Token, usize)>, (usize, usize)> {
+    while pos < input.len() && input[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+
+    if pos == input.len() {
+        return Ok(None);
+    }
+
+    match input[pos] {
+        b'[' => return Ok(Some((Token::CaptureStart, pos + 1))),
+        b']' => return Ok(Some((Token::CaptureEnd, pos + 1))),
+        _ => (),
+    }
+
+    let mut end = pos;
+
+    while end < input.len()
+        && !input[end].is_ascii_whitespace()
+        && !matches!(input[end], b'[' | b']')
+    {
+        end += 1;
+    }
+
+    let len = end - pos;
+
+    if len == 1 && is_wildcard(input[pos]) {
+        return Ok(Some((Token::Byte(SignatureByte::Any), end)));
+    }
+
+    if len == 5 && input[pos + 2] == b'&' {
+        let value = parse_pair(input[pos], input[pos + 1]);
+        let mask = parse_pair(input[pos + 3], input[pos + 4]);
+
+        return match (value, mask) {
+            (
+                Some(SignatureByte::Byte(value)),
+                Some(SignatureByte::Byte(mask)),
+            ) => {
+                Ok(Some((Token::Byte(SignatureByte::masked(value, mask)), end)))
+            }
+            _ => Err((pos, len)),
+        };
+    }
+
+    if !len.is_multiple_of(2) {
+        return Err((pos, len));
+    }
+
+    match parse_pair(input[pos], input[pos + 1]) {
+        Some(byte) => Ok(Some((Token::Byte(byte), pos + 2))),
+        None => Err((pos, len)),
+    }
+}
+
+/// Error of [`parse_into()`], positions are byte offsets in the input
+enum RawError {
+    Empty,
+    Token { position: usize, len: usize },
+    Capture(usize),
+}
+
+impl RawError {
+    fn into_error(self, value: &str) -> ParseSignatureError {
+        match self {
+            RawError::Empty => ParseSignatureError::Empty,
+            RawError::Token { position, len } => {
+                ParseSignatureError::InvalidToken {
+                    position,
+                    token: value[position..position + len].to_owned(),
+                }
+            }
+            RawError::Capture(position) => {
+                ParseSignatureError::InvalidCapture(position)
+            }
+        }
+    }
+
+    /// Const evaluation can't format messages, so only kind of
+    /// the error is reported
+    const fn panic(self) -> ! {
+        match self {
+            RawError::Empty => panic!("signature is empty"),
+            RawError::Token { .. } => panic!("invalid token in signature"),
+            RawError::Capture(_) => panic!(
+                "invalid capture in signature, \
+                expected exactly one `[..]` group"
+            ),
+        }
+    }
+}
+
+/// Parses `input` writing bytes into `out` while they fit, returns
+/// total amount of bytes and the capture.
+///
+/// Called once with empty `out` to count bytes and then with
+/// a buffer of that size, which works both at runtime and in const
+/// context.
+const fn parse_into(
+    input: &[u8],
+    out: &mut [SignatureByte],
+) -> Result<(usize, Option<Capture>), RawError> {
+    let mut count = 0;
+
+    let mut capture = None;
+    // Position of `[` and amount of bytes before it
+    let mut capture_start: Option<(usize, usize)> = None;
+
+    let mut pos = 0;
+
+    loop {
+        let (token, next) = match next_token(input, pos) {
+            Ok(Some(token)) => token,
+            Ok(None) => break,
+            Err((position, len)) => {
+                return Err(RawError::Token { position, len })
+            }
+        };
+
+        match token {
+            Token::Byte(byte) => {
+                if count < out.len() {
+                    out[count] = byte;
+                }
+
+                count += 1;
+            }
+            Token::CaptureStart => {
+                if capture.is_some() || capture_start.is_some() {
+                    return Err(RawError::Capture(next - 1));
+                }
+
+                capture_start = Some((next - 1, count));
+            }
+            Token::CaptureEnd => {
+                let offset = match capture_start {
+                    Some((_, offset)) if offset != count => offset,
+                    _ => return Err(RawError::Capture(next - 1)),
+                };
+
+                capture_start = None;
+                capture = Some(Capture {
+                    offset,
+                    len: count - offset,
+                });
+            }
+        }
+
+        pos = next;
+    }
+
+    if let Some((position, _)) = capture_start {
+        return Err(RawError::Capture(position));
+    }
+
+    if count == 0 {
+        return Err(RawError::Empty);
+    }
+
+    Ok((count, capture))
 }
 
 impl FromStr for Signature {
     type Err = ParseSignatureError;
 
+    /// Accepts bytes separated by any whitespace or written together,
+    /// `??`, `?` or `*` wildcards, half-wildcards like `4?` / `?F`,
+    /// explicit masks like `40&F8` and a single `[..]` capture group.
+    ///
+    /// `E8 ? ? ? ?`, `48 8B05????????` and `48 8B 05 ?? ?? ?? ??`
+    /// are all the same signature.
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        if value.len() % 3 != 2 {
-            return Err(ParseSignatureError::InvalidLength(value.len()));
+        let input = value.as_bytes();
+        let mut bytes = Vec::new();
+
+        let res = parse_into(input, &mut []).and_then(|(len, _)| {
+            bytes.resize(len, SignatureByte::Any);
+            parse_into(input, &mut bytes)
+        });
+
+        match res {
+            Ok((_, capture)) => Ok(Self {
+                bytes: Cow::Owned(bytes),
+                capture,
+            }),
+            Err(e) => Err(e.into_error(value)),
+        }
+    }
+}
+
+/// Amount of bytes in the `pattern`, used by
+/// [`signature!`](crate::signature!)
+#[doc(hidden)]
+pub const fn __signature_len(pattern: &str) -> usize {
+    match parse_into(pattern.as_bytes(), &mut []) {
+        Ok((len, _)) => len,
+        Err(e) => e.panic(),
+    }
+}
+
+/// Parses `pattern` of `N` bytes, used by
+/// [`signature!`](crate::signature!)
+#[doc(hidden)]
+pub const fn __parse_signature<const N: usize>(
+    pattern: &str,
+) -> ([SignatureByte; N], Option<Capture>) {
+    let mut bytes = [SignatureByte::Any; N];
+
+    match parse_into(pattern.as_bytes(), &mut bytes) {
+        Ok((_, capture)) => (bytes, capture),
+        Err(e) => e.panic(),
+    }
+}
+
+/// Parses a [`Signature`] at compile time, so invalid pattern is
+/// a compile error instead of a runtime one. Accepts the same syntax
+/// as [`Signature::from_str()`].
+///
+/// ```
+/// use rosu_mem::{signature, signature::Signature};
+///
+/// static BASE: Signature = signature!("F8 01 74 04 83 65");
+/// const STATUS: Signature = signature!("[?? ?? ?? ??] 48 83 F8 04 73 1E");
+///
+/// assert_eq!(BASE.len(), 6);
+/// assert_eq!(STATUS.capture().unwrap().len, 4);
+/// ```
+///
+/// ```compile_fail
+/// let sign = rosu_mem::signature!("F8 01 7G");
+/// ```
+#[macro_export]
+macro_rules! signature {
+    ($pattern:expr) => {{
+        const LEN: usize = $crate::signature::__signature_len($pattern);
+        const PARSED: (
+            [$crate::signature::SignatureByte; LEN],
+            ::core::option::Option<$crate::signature::Capture>,
+        ) = $crate::signature::__parse_signature::<LEN>($pattern);
+        const BYTES: [$crate::signature::SignatureByte; LEN] = PARSED.0;
+        const SIGNATURE: $crate::signature::Signature =
+            $crate::signature::Signature::from_static(&BYTES, PARSED.1);
+
+        SIGNATURE
+    }};
+}
+
+impl Signature {
+    /// Creates a signature without allocating, which is usable in
+    /// `const` and `static` items. Prefer
+    /// [`signature!`](crate::signature!) that also parses a pattern at
+    /// compile time.
+    ///
+    /// # Panics
+    ///
+    /// If `bytes` is empty or `capture` is empty or out of bounds
+    pub const fn from_static(
+        bytes: &'static [SignatureByte],
+        capture: Option<Capture>,
+    ) -> Self {
+        assert!(!bytes.is_empty(), "signature is empty");
+
+        if let Some(capture) = capture {
+            assert!(
+                capture.len != 0 && capture.offset + capture.len <= bytes.len(),
+                "capture is out of signature bounds"
+            );
+        }
+
+        Self {
+            bytes: Cow::Borrowed(bytes),
+            capture,
         }
+    }
+
+    #[inline]
+    pub fn bytes(&self) -> &[SignatureByte] {
+        &self.bytes
+    }
 
-        let capacity = value.len().div_ceil(3);
-        let mut bytes = Vec::with_capacity(capacity);
+    /// Part of the signature marked with `[ ]`
+    #[inline]
+    pub fn capture(&self) -> Option<Capture> {
+        self.capture
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Picks a part of the signature that is going to be searched
+    /// first, every hit of it is then verified against whole signature.
+    ///
+    /// Returns offset of the anchor inside of signature and
+    /// anchor bytes. Longest run of fixed bytes is used, if every run
+    /// is just one byte long then the rarest byte is picked instead.
+    fn anchor(&self) -> (usize, Vec<u8>) {
+        let mut best = (0, 0); // (offset, len)
+        let mut run_start = 0;
+
+        for (i, byte) in self.bytes.iter().enumerate() {
+            match byte {
+                SignatureByte::Byte(_) => {
+                    let run_len = i + 1 - run_start;
+
+                    if run_len > best.1 {
+                        best = (run_start, run_len);
+                    }
+                }
+                _ => run_start = i + 1,
+            }
+        }
 
-        for c in value.split(' ') {
-            bytes.push(c.parse()?);
+        if best.1 == 1 {
+            best = self
+                .bytes
+                .iter()
+                .enumerate()
+                .filter_map(|(i, byte)| match byte {
+                    SignatureByte::Byte(b) => Some((i, *b)),
+                    _ => None,
+                })
+                .min_by_key(|(_, b)| byte_commonness(*b))
+                .map(|(i, _)| (i, 1))
+                .unwrap_or(best);
         }
 
-        // making sure there is no excess capacity so converting
-        // from Vec to Box does not re-allocate
-        debug_assert_eq!(bytes.len(), bytes.capacity());
+        let (offset, len) = best;
+
+        let anchor = self.bytes[offset..offset + len]
+            .iter()
+            .map(|byte| match byte {
+                SignatureByte::Byte(b) => *b,
+                _ => unreachable!(),
+            })
+            .collect();
+
+        (offset, anchor)
+    }
+}
+
+/// Rough rank of how often byte is seen in x86 code and data,
+/// lower is rarer
+fn byte_commonness(byte: u8) -> usize {
+    const COMMON: [u8; 16] = [
+        0x00, 0xFF, 0x48, 0x8B, 0x89, 0x0F, 0x01, 0x24, 0xE8, 0x83, 0x4C, 0x85,
+        0xC0, 0x8D, 0xCC, 0x90,
+    ];
+
+    COMMON
+        .iter()
+        .position(|b| *b == byte)
+        .map(|pos| COMMON.len() - pos)
+        .unwrap_or(0)
+}
+
+/// Iterator over signature matches, see [`find_all_signatures`]
+pub struct SignatureMatches<'a> {
+    buff: &'a [u8],
+    sign: &'a Signature,
+    finder: Finder<'static>,
+    anchor_offset: usize,
+    pos: usize,
+}
+
+impl Iterator for SignatureMatches<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.pos <= self.buff.len() {
+            let hit = self.pos + self.finder.find(&self.buff[self.pos..])?;
+            self.pos = hit + 1;
 
-        Ok(Self {
-            bytes: bytes.into_boxed_slice(),
-        })
+            let Some(start) = hit.checked_sub(self.anchor_offset) else {
+                continue;
+            };
+
+            let window = self.buff.get(start..start + self.sign.len())?;
+
+            if self.sign.bytes.as_ref() == window {
+                return Some(start);
+            }
+        }
+
+        None
     }
 }
 
 impl Display for Signature {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let mut bytes = self.bytes.iter();
+        for (i, byte) in self.bytes.iter().enumerate() {
+            if i != 0 {
+                f.write_str(" ")?;
+            }
+
+            let capture = self
+                .capture
+                .filter(|c| (c.offset..c.offset + c.len).contains(&i));
+
+            if capture.is_some_and(|c| c.offset == i) {
+                f.write_str("[")?;
+            }
 
-        if let Some(byte) = bytes.next() {
             Display::fmt(byte, f)?;
 
-            for byte in bytes {
-                write!(f, " {byte}")?;
+            if capture.is_some_and(|c| c.offset + c.len == i + 1) {
+                f.write_str("]")?;
             }
         }
 
@@ -89,16 +598,44 @@ impl Display for Signature {
 /// Find signature inside of [u8] buffer
 #[inline]
 pub fn find_signature(buff: &[u8], sign: &Signature) -> Option<usize> {
-    buff.windows(sign.bytes.len())
-        .enumerate()
-        .find_map(|(i, window)| (sign.bytes.as_ref() == window).then_some(i))
+    find_all_signatures(buff, sign).next()
+}
+
+/// Find every signature match inside of [u8] buffer.
+///
+/// Matches are allowed to overlap
+///
+/// Notes:
+/// * Instead of comparing every window of the buffer, fixed part of
+///   the signature (anchor) is searched with `memchr` first and only
+///   its hits are compared with whole signature
+#[inline]
+pub fn find_all_signatures<'a>(
+    buff: &'a [u8],
+    sign: &'a Signature,
+) -> SignatureMatches<'a> {
+    let (anchor_offset, anchor) = sign.anchor();
+
+    SignatureMatches {
+        buff,
+        sign,
+        finder: Finder::new(&anchor).into_owned(),
+        anchor_offset,
+        pos: 0,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::signature::{find_signature, Signature, SignatureByte};
+    use crate::{
+        error::ParseSignatureError,
+        signature::{
+            find_all_signatures, find_signature, Capture, Signature,
+            SignatureByte,
+        },
+    };
 
     #[test]
     fn test_find_sig() {
@@ -138,6 +675,60 @@ mod tests {
         assert_eq!(s, 0);
     }
 
+    #[test]
+    fn test_find_all_sigs() {
+        //              0     1     2     3     4     5     6     7
+        let buff = vec![0xFF, 0x30, 0xFF, 0x30, 0xFF, 0x30, 0x2B, 0xCB];
+
+        let sig = Signature::from_str("FF 30").unwrap();
+        let s: Vec<usize> = find_all_signatures(&buff, &sig).collect();
+        assert_eq!(s, [0, 2, 4]);
+
+        // Overlapping matches
+        let sig = Signature::from_str("FF 30 FF").unwrap();
+        let s: Vec<usize> = find_all_signatures(&buff, &sig).collect();
+        assert_eq!(s, [0, 2]);
+
+        let sig = Signature::from_str("FF ?? ?? ?? 2B").unwrap();
+        let s: Vec<usize> = find_all_signatures(&buff, &sig).collect();
+        assert_eq!(s, [2]);
+
+        let sig = Signature::from_str("AB CD").unwrap();
+        assert_eq!(find_all_signatures(&buff, &sig).count(), 0);
+    }
+
+    #[test]
+    fn test_find_sig_randomized() {
+        use rand::prelude::*;
+
+        let mut rng = thread_rng();
+
+        for _ in 0..256 {
+            // Small alphabet so there is a lot of partial matches
+            let buff: Vec<u8> =
+                (0..1024).map(|_| rng.gen_range(0..4)).collect();
+
+            let sig: Vec<String> = (0..rng.gen_range(1..6))
+                .map(|_| match rng.gen_range(0..5) {
+                    0 => "??".to_string(),
+                    v => format!("{:02X}", v - 1),
+                })
+                .collect();
+
+            let sig = Signature::from_str(&sig.join(" ")).unwrap();
+
+            let expected: Vec<usize> = buff
+                .windows(sig.len())
+                .enumerate()
+                .filter_map(|(i, w)| (sig.bytes() == w).then_some(i))
+                .collect();
+
+            let found: Vec<usize> = find_all_signatures(&buff, &sig).collect();
+
+            assert_eq!(found, expected, "signature: {sig}");
+        }
+    }
+
     #[test]
     fn test_signature_parsing() {
         let s = Signature::from_str("FF 30 A3 50").unwrap();
@@ -169,6 +760,130 @@ mod tests {
         assert_eq!(s, 0xFF);
         assert_eq!(s, 0xF3);
         assert_eq!(s, 0xCB);
+
+        // Same tokens as in signatures
+        assert_eq!(SignatureByte::from_str("?").unwrap(), SignatureByte::Any);
+        assert_eq!(SignatureByte::from_str("*").unwrap(), SignatureByte::Any);
+        assert_eq!(
+            SignatureByte::from_str(" ab ").unwrap(),
+            SignatureByte::Byte(0xAB)
+        );
+
+        assert!(matches!(
+            SignatureByte::from_str(""),
+            Err(ParseSignatureError::Empty)
+        ));
+
+        for invalid in ["ABCD", "AB CD", "[AB]", "ABC", "4&F"] {
+            assert!(
+                matches!(
+                    SignatureByte::from_str(invalid),
+                    Err(ParseSignatureError::InvalidToken { .. })
+                ),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_signature_capture() {
+        let s = Signature::from_str("48 8B 05 [?? ?? ?? ??] 48 85 C0").unwrap();
+        assert_eq!(s.bytes.len(), 10);
+        assert_eq!(s.capture(), Some(Capture { offset: 3, len: 4 }));
+
+        let s = Signature::from_str("[??] FF").unwrap();
+        assert_eq!(s.capture(), Some(Capture { offset: 0, len: 1 }));
+
+        let s = Signature::from_str("FF [?? ??]").unwrap();
+        assert_eq!(s.capture(), Some(Capture { offset: 1, len: 2 }));
+
+        let s = Signature::from_str("FF 30").unwrap();
+        assert_eq!(s.capture(), None);
+
+        for invalid in ["FF [?? ??", "FF ??]", "[??] [??]", "[] FF"] {
+            assert!(
+                matches!(
+                    Signature::from_str(invalid),
+                    Err(ParseSignatureError::InvalidCapture(_))
+                ),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_signature_byte_masked() {
+        let s = SignatureByte::from_str("4?").unwrap();
+        assert_eq!(
+            s,
+            SignatureByte::Masked {
+                value: 0x40,
+                mask: 0xF0
+            }
+        );
+        assert_eq!(s, 0x40);
+        assert_eq!(s, 0x48);
+        assert_eq!(s, 0x4F);
+        assert_ne!(s, 0x50);
+        assert_ne!(s, 0x04);
+
+        let s = SignatureByte::from_str("?8").unwrap();
+        assert_eq!(s, 0x08);
+        assert_eq!(s, 0xC8);
+        assert_ne!(s, 0x80);
+        assert_ne!(s, 0xC9);
+
+        // mov r/m, reg with any register: 89 C? / 89 11xxxxxx
+        let s = SignatureByte::from_str("C0&C0").unwrap();
+        assert_eq!(s, 0xC0);
+        assert_eq!(s, 0xC3);
+        assert_eq!(s, 0xFF);
+        assert_ne!(s, 0x80);
+
+        let s = SignatureByte::from_str("AB&FF").unwrap();
+        assert_eq!(s, SignatureByte::Byte(0xAB));
+
+        let s = SignatureByte::from_str("AB&00").unwrap();
+        assert_eq!(s, SignatureByte::Any);
+
+        // Bits of the value outside of the mask are ignored
+        let s = SignatureByte::Masked {
+            value: 0xFF,
+            mask: 0xF0,
+        };
+        assert_eq!(s, 0xF0);
+        assert_eq!(s, 0xFA);
+        assert_ne!(s, 0x0F);
+        assert_eq!(s.to_string(), "F?");
+
+        let s = SignatureByte::Masked {
+            value: 0xFF,
+            mask: 0x0F,
+        };
+        assert_eq!(s, 0x0F);
+        assert_eq!(s.to_string(), "?F");
+
+        assert!(SignatureByte::from_str("G?").is_err());
+        assert!(SignatureByte::from_str("?G").is_err());
+        assert!(SignatureByte::from_str("AB&").is_err());
+    }
+
+    #[test]
+    fn test_find_sig_masked() {
+        //              0     1     2     3     4     5     6     7
+        let buff = vec![0x48, 0x89, 0xC8, 0x48, 0x89, 0xD9, 0x2B, 0xCB];
+
+        let sig = Signature::from_str("48 89 C?").unwrap();
+        let s: Vec<usize> = find_all_signatures(&buff, &sig).collect();
+        assert_eq!(s, [0]);
+
+        let sig = Signature::from_str("48 89 C0&C0").unwrap();
+        let s: Vec<usize> = find_all_signatures(&buff, &sig).collect();
+        assert_eq!(s, [0, 3]);
+
+        let sig = Signature::from_str("4? ?9 ?9").unwrap();
+        let s: Vec<usize> = find_all_signatures(&buff, &sig).collect();
+        assert_eq!(s, [3]);
     }
 
     #[test]
@@ -178,5 +893,91 @@ mod tests {
         assert_eq!(s.bytes.len(), 5);
 
         assert_eq!(expected, s.to_string());
+
+        let expected = "48 8B 05 [?? ?? ?? ??] 48 85 C0";
+        let s = Signature::from_str(expected).unwrap();
+        assert_eq!(expected, s.to_string());
+
+        let expected = "[FF] 30";
+        let s = Signature::from_str(expected).unwrap();
+        assert_eq!(expected, s.to_string());
+
+        let expected = "48 8? ?5 C0&C0 [?? ??]";
+        let s = Signature::from_str(expected).unwrap();
+        assert_eq!(expected, s.to_string());
+    }
+
+    #[test]
+    fn test_signature_dialects() {
+        let expected = Signature::from_str("E8 ?? ?? ?? ?? 48 8B 05").unwrap();
+
+        for dialect in [
+            "E8 ? ? ? ? 48 8B 05",
+            "E8 * * * * 48 8B 05",
+            "E8????????488B05",
+            "E8 ???????? 48 8B05",
+            "  e8\t??  ?? ?? ??\n48 8b 05 ",
+        ] {
+            let s = Signature::from_str(dialect).unwrap();
+            assert_eq!(s.bytes(), expected.bytes(), "{dialect}");
+        }
+
+        let s = Signature::from_str("48 8B05[????????]4885C0").unwrap();
+        assert_eq!(s.len(), 10);
+        assert_eq!(s.capture(), Some(Capture { offset: 3, len: 4 }));
+
+        let s = Signature::from_str("4?8* C0&C0").unwrap();
+        assert_eq!(s.to_string(), "4? 8? C0&C0");
+    }
+
+    #[test]
+    fn test_signature_errors() {
+        let invalid_token = |value: &str| match Signature::from_str(value) {
+            Err(ParseSignatureError::InvalidToken { position, token }) => {
+                (position, token)
+            }
+            res => panic!("{value}: {res:?}"),
+        };
+
+        assert_eq!(invalid_token("48 8G"), (3, "8G".to_owned()));
+        assert_eq!(invalid_token("48 8B0 C0"), (3, "8B0".to_owned()));
+        assert_eq!(invalid_token("48  ??? C0"), (4, "???".to_owned()));
+        assert_eq!(invalid_token("48, 8B"), (0, "48,".to_owned()));
+        assert_eq!(invalid_token("48 C0&G0"), (3, "C0&G0".to_owned()));
+        assert_eq!(invalid_token("48 8BXY"), (5, "XY".to_owned()));
+
+        for empty in ["", "   ", "\t\n"] {
+            assert!(matches!(
+                Signature::from_str(empty),
+                Err(ParseSignatureError::Empty)
+            ));
+        }
+
+        assert!(matches!(
+            Signature::from_str("48 [8B"),
+            Err(ParseSignatureError::InvalidCapture(3))
+        ));
+        assert!(matches!(
+            Signature::from_str("[48] 8B ]"),
+            Err(ParseSignatureError::InvalidCapture(8))
+        ));
+    }
+
+    #[test]
+    fn test_signature_macro() {
+        const SIGN: Signature = crate::signature!("48 8B05 [? ? ? ?] 4? C0&C0");
+        static BASE: Signature = crate::signature!("F8 01 74 04 83 65");
+
+        let expected =
+            Signature::from_str("48 8B 05 [?? ?? ?? ??] 4? C0&C0").unwrap();
+        assert_eq!(SIGN.bytes(), expected.bytes());
+        assert_eq!(SIGN.capture(), expected.capture());
+
+        assert_eq!(BASE.len(), 6);
+        assert_eq!(BASE.capture(), None);
+
+        let buff = [0x00, 0xF8, 0x01, 0x74, 0x04, 0x83, 0x65];
+        assert_eq!(find_signature(&buff, &BASE), Some(1));
+        assert_eq!(find_signature(&buff, &crate::signature!("74 ?")), Some(3));
     }
 }
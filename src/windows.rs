@@ -1,31 +1,105 @@
-use std::{
-    ffi::{c_uint, c_void},
-    path::PathBuf,
-};
+use std::{ffi::c_void, path::PathBuf};
 
 use windows::Win32::{
     Foundation::HMODULE,
     System::{
-        Diagnostics::Debug::ReadProcessMemory,
-        Memory::{VirtualQueryEx, MEMORY_BASIC_INFORMATION, MEM_FREE},
-        ProcessStatus::{EnumProcesses, GetModuleFileNameExA},
+        Diagnostics::Debug::{ReadProcessMemory, WriteProcessMemory},
+        Memory::{
+            VirtualQueryEx, MEMORY_BASIC_INFORMATION, MEM_FREE, MEM_IMAGE,
+            MEM_MAPPED, PAGE_EXECUTE, PAGE_EXECUTE_READ,
+            PAGE_EXECUTE_READWRITE, PAGE_EXECUTE_WRITECOPY, PAGE_GUARD,
+            PAGE_PROTECTION_FLAGS, PAGE_READONLY, PAGE_READWRITE,
+            PAGE_WRITECOPY,
+        },
+        ProcessStatus::{
+            EnumProcesses, GetMappedFileNameW, GetModuleFileNameExA,
+        },
     },
 };
 
 use crate::{
+    arch::TargetArch,
     process::{MemoryRegion, Process, ProcessTraits},
-    signature::find_signature,
+    region::{Protection, RegionKind},
 };
 
-use super::{error::ProcessError, signature::Signature};
+use super::error::ProcessError;
 
 use windows::Win32::{
     Foundation::{CloseHandle, FALSE, HANDLE},
     System::Threading::{
-        OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_READ,
+        IsWow64Process, OpenProcess, PROCESS_QUERY_INFORMATION,
+        PROCESS_VM_OPERATION, PROCESS_VM_READ, PROCESS_VM_WRITE,
     },
 };
 
+fn protection(flags: PAGE_PROTECTION_FLAGS) -> Protection {
+    let has = |mask: &[PAGE_PROTECTION_FLAGS]| {
+        mask.iter().any(|flag| flags.0 & flag.0 != 0)
+    };
+
+    if has(&[PAGE_GUARD]) {
+        return Protection::default();
+    }
+
+    Protection::new(
+        has(&[
+            PAGE_READONLY,
+            PAGE_READWRITE,
+            PAGE_WRITECOPY,
+            PAGE_EXECUTE_READ,
+            PAGE_EXECUTE_READWRITE,
+            PAGE_EXECUTE_WRITECOPY,
+        ]),
+        has(&[
+            PAGE_READWRITE,
+            PAGE_WRITECOPY,
+            PAGE_EXECUTE_READWRITE,
+            PAGE_EXECUTE_WRITECOPY,
+        ]),
+        has(&[
+            PAGE_EXECUTE,
+            PAGE_EXECUTE_READ,
+            PAGE_EXECUTE_READWRITE,
+            PAGE_EXECUTE_WRITECOPY,
+        ]),
+    )
+}
+
+impl Process {
+    /// WOW64 processes are 32 bit ones running on 64 bit windows,
+    /// everything else has the same architecture as windows itself
+    fn arch_of(handle: HANDLE) -> Result<TargetArch, ProcessError> {
+        let mut wow64 = FALSE;
+        unsafe { IsWow64Process(handle, &mut wow64) }.ok()?;
+
+        if wow64.as_bool() {
+            Ok(TargetArch::X86)
+        } else {
+            Ok(TargetArch::HOST)
+        }
+    }
+
+    /// Path of the file mapped at `addr`.
+    ///
+    /// Notes:
+    /// * Path is in the device form, e.g.
+    ///   `\Device\HarddiskVolume3\osu!\osu!.exe`
+    fn mapped_file_name(&self, addr: *const c_void) -> Option<PathBuf> {
+        let mut buff = [0u16; 1024];
+
+        let len = unsafe { GetMappedFileNameW(self.handle, addr, &mut buff) };
+
+        if len == 0 {
+            return None;
+        }
+
+        Some(PathBuf::from(String::from_utf16_lossy(
+            &buff[..len as usize],
+        )))
+    }
+}
+
 impl ProcessTraits for Process {
     fn initialize(
         proc_name: &str,
@@ -86,6 +160,14 @@ impl ProcessTraits for Process {
                     }
                 }
 
+                let arch = match Process::arch_of(handle) {
+                    Ok(arch) => arch,
+                    Err(e) => {
+                        unsafe { CloseHandle(handle) };
+                        return Err(e);
+                    }
+                };
+
                 let executable_path = PathBuf::from(name);
                 let executable_dir =
                     executable_path.parent().map(|v| v.to_path_buf());
@@ -95,6 +177,9 @@ impl ProcessTraits for Process {
                     handle,
                     maps: Vec::new(),
                     executable_dir,
+                    executable_path: Some(executable_path),
+                    arch,
+                    arch_detected: true,
                 });
             } else {
                 unsafe { CloseHandle(handle) };
@@ -120,9 +205,24 @@ impl ProcessTraits for Process {
             address = (info.BaseAddress as usize) + info.RegionSize;
 
             if info.State != MEM_FREE {
+                let kind = if info.Type == MEM_IMAGE || info.Type == MEM_MAPPED
+                {
+                    RegionKind::File
+                } else {
+                    RegionKind::Anonymous
+                };
+
+                let path = match kind {
+                    RegionKind::File => self.mapped_file_name(info.BaseAddress),
+                    _ => None,
+                };
+
                 self.maps.push(MemoryRegion {
                     from: info.BaseAddress as usize,
                     size: info.RegionSize,
+                    protection: protection(info.Protect),
+                    kind,
+                    path,
                 })
             }
         }
@@ -130,45 +230,47 @@ impl ProcessTraits for Process {
         Ok(self)
     }
 
-    fn read_signature<T: TryFrom<usize>>(
+    fn regions(&self) -> &[MemoryRegion] {
+        &self.maps
+    }
+
+    fn arch(&self) -> TargetArch {
+        self.arch
+    }
+
+    fn read_region(
         &self,
-        sign: &Signature,
-    ) -> Result<T, ProcessError> {
-        let mut buf = Vec::new();
+        region: &MemoryRegion,
+        buff: &mut Vec<u8>,
+    ) -> Result<bool, ProcessError> {
         let mut bytesread: usize = 0;
 
-        for region in self.maps.iter() {
-            buf.resize(region.size, 0);
-
-            let res = unsafe {
-                ReadProcessMemory(
-                    self.handle,
-                    region.from as c_uint as *mut c_void,
-                    buf.as_mut_ptr() as *mut c_void,
-                    region.size,
-                    Some(&mut bytesread),
-                )
-            };
+        buff.resize(region.size, 0);
 
-            if let Err(error) = res.ok() {
-                // Stupid error code that we should
-                // ignore during memory regions
-                // collection
-                if error.code().0 == -2147024597 {
-                    continue;
-                }
+        let res = unsafe {
+            ReadProcessMemory(
+                self.handle,
+                region.from as *mut c_void,
+                buff.as_mut_ptr() as *mut c_void,
+                region.size,
+                Some(&mut bytesread),
+            )
+        };
 
-                return Err(error.into());
+        if let Err(error) = res.ok() {
+            // Stupid error code that we should
+            // ignore during memory regions
+            // collection
+            if error.code().0 == -2147024597 {
+                return Ok(false);
             }
 
-            if let Some(offset) = find_signature(&buf[..bytesread], sign) {
-                return (region.from + offset)
-                    .try_into()
-                    .map_err(|_| ProcessError::AddressConvertError);
-            }
+            return Err(error.into());
         }
 
-        Err(ProcessError::SignatureNotFound(sign.to_string()))
+        buff.truncate(bytesread);
+
+        Ok(true)
     }
 
     fn read<T: TryInto<usize>>(
@@ -186,7 +288,7 @@ impl ProcessTraits for Process {
         let res = unsafe {
             ReadProcessMemory(
                 self.handle as HANDLE,
-                addr as c_uint as *mut c_void,
+                addr as *mut c_void,
                 buff.as_mut_ptr() as *mut c_void,
                 len,
                 Some(&mut n),
@@ -201,4 +303,46 @@ impl ProcessTraits for Process {
 
         Ok(())
     }
+
+    fn write<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        buff: &[u8],
+    ) -> Result<(), ProcessError> {
+        let addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        // Main handle is read-only, so read-only consumers don't need
+        // write rights to the process
+        let handle = unsafe {
+            OpenProcess(
+                PROCESS_VM_WRITE | PROCESS_VM_OPERATION,
+                FALSE,
+                self.pid,
+            )
+        }?;
+
+        let mut n = 0;
+
+        let res = unsafe {
+            WriteProcessMemory(
+                handle,
+                addr as *const c_void,
+                buff.as_ptr() as *const c_void,
+                buff.len(),
+                Some(&mut n),
+            )
+        };
+
+        unsafe { CloseHandle(handle) };
+
+        res.ok()?;
+
+        if n != buff.len() {
+            return Err(ProcessError::BadAddress(addr, buff.len()));
+        }
+
+        Ok(())
+    }
 }
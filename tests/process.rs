@@ -1,4 +1,5 @@
 use rosu_mem::{
+    batch::ReadBatch,
     error::ProcessError,
     process::{Process, ProcessTraits},
 };
@@ -82,3 +83,134 @@ fn test_process_finder_exclude() {
 
     assert!(matches!(err, ProcessError::ProcessNotFound));
 }
+
+/// Writing into memory of current process and reading it back
+#[test]
+fn test_process_write() {
+    let proc_id = std::process::id();
+    let name = get_process_name(proc_id);
+
+    let proc = Process::find_process(&name, &[]).unwrap();
+
+    let value = Box::new(0u64);
+    let addr = &*value as *const u64 as usize;
+
+    proc.write_u64(addr, 0xDEADBEEF).unwrap();
+
+    assert_eq!(proc.read_u64(addr).unwrap(), 0xDEADBEEF);
+}
+
+/// Reading a lot of values from current process in one batch, with
+/// one bad address in the middle
+#[test]
+fn test_process_read_batch() {
+    let proc_id = std::process::id();
+    let name = get_process_name(proc_id);
+
+    let proc = Process::find_process(&name, &[]).unwrap();
+
+    let values: Vec<u32> = (0..3000).collect();
+    let mut outputs = vec![[0u8; 4]; values.len()];
+
+    const BAD_ENTRY: usize = 1500;
+
+    let mut batch = ReadBatch::with_capacity(values.len());
+    for (i, (value, output)) in
+        values.iter().zip(outputs.iter_mut()).enumerate()
+    {
+        let addr = if i == BAD_ENTRY {
+            0x8
+        } else {
+            value as *const u32 as usize
+        };
+
+        assert_eq!(batch.push(addr, output), i);
+    }
+
+    let results = proc.read_batch(&mut batch).unwrap();
+    drop(batch);
+
+    assert_eq!(results.len(), values.len());
+
+    for (i, result) in results.iter().enumerate() {
+        if i == BAD_ENTRY {
+            assert!(matches!(result, Err(ProcessError::BadAddress(0x8, 4))));
+        } else {
+            assert!(result.is_ok());
+            assert_eq!(u32::from_le_bytes(outputs[i]), values[i]);
+        }
+    }
+}
+
+/// Test executable itself should be listed as a module containing
+/// its own code
+#[test]
+fn test_process_modules() {
+    let proc_id = std::process::id();
+    let name = get_process_name(proc_id);
+
+    let proc = Process::initialize(&name, &[]).unwrap();
+
+    let exe = std::env::current_exe().unwrap();
+    let exe_name = exe.file_name().unwrap().to_str().unwrap();
+
+    let module = proc.module(exe_name).unwrap();
+    assert_eq!(module.name, exe_name);
+    assert!(module.contains(test_process_modules as *const () as usize));
+
+    assert!(proc.modules().contains(&module));
+}
+
+/// Architecture is detected from the test executable image
+#[test]
+fn test_process_arch() {
+    use rosu_mem::arch::TargetArch;
+
+    let proc_id = std::process::id();
+    let name = get_process_name(proc_id);
+
+    let proc = Process::initialize(&name, &[]).unwrap();
+    assert_eq!(proc.arch(), TargetArch::HOST);
+    assert!(proc.arch_detected);
+
+    let exe = std::env::current_exe().unwrap();
+    let exe_name = exe.file_name().unwrap().to_str().unwrap();
+    assert_eq!(TargetArch::detect(&proc, exe_name), Some(TargetArch::HOST));
+    assert_eq!(TargetArch::detect(&proc, "missing.exe"), None);
+}
+
+/// Resolving libc symbol of current process from its dynamic symbol
+/// table and comparing it with the address linker gave us
+#[cfg(target_os = "linux")]
+#[test]
+fn test_process_elf_symbols() {
+    use rosu_mem::{elf::ElfImage, pointer::PointerWidth};
+
+    let proc_id = std::process::id();
+    let name = get_process_name(proc_id);
+
+    let proc = Process::initialize(&name, &[]).unwrap();
+
+    let libc = proc
+        .modules()
+        .into_iter()
+        .find(|m| m.name.starts_with("libc.so") || m.name.starts_with("libc-"))
+        .unwrap();
+
+    let image = ElfImage::read(&proc, libc.base).unwrap();
+    assert_eq!(image.width, PointerWidth::X64);
+    assert!(image.segments.iter().any(|s| s.is_executable()));
+
+    let getpid = nix::libc::getpid as *const () as usize;
+    assert_eq!(image.symbol(&proc, "getpid").unwrap(), Some(getpid));
+
+    let symbols = image.symbols(&proc).unwrap();
+    assert!(symbols.iter().any(|s| s.name == "malloc"));
+
+    let exe = std::env::current_exe().unwrap();
+    let exe_name = exe.file_name().unwrap().to_str().unwrap();
+    let module = proc.module(exe_name).unwrap();
+
+    let res = ElfImage::read(&proc, module.base + 0x10);
+    assert!(matches!(res, Err(ProcessError::InvalidImage(_))));
+}
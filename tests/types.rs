@@ -1,8 +1,17 @@
 use paste::paste;
 use rand::{distributions::Alphanumeric, prelude::*};
-use std::io::Read;
-
-use rosu_mem::{error::*, process::ProcessTraits};
+use std::{io::Read, sync::RwLock};
+
+use rosu_mem::{
+    arch::TargetArch,
+    error::*,
+    pod::Pod,
+    pointer::{PointerChain, PointerWidth},
+    process::{MemoryRegion, ProcessTraits},
+    region::{Protection, RegionFilter, RegionKind},
+    signature::{CaptureKind, Signature},
+};
+use std::str::FromStr;
 
 macro_rules! prim_read_test {
     ($t: ident) => {
@@ -13,9 +22,7 @@ macro_rules! prim_read_test {
                 let num: $t = rng.gen();
 
                 let buff: Vec<u8> = num.to_le_bytes().to_vec();
-                let p = FakeProccess {
-                    buff
-                };
+                let p = FakeProccess::new(buff);
 
                 let tmp = p.[<read_ $t>](0).unwrap();
                 assert_eq!(tmp, num);
@@ -25,6 +32,27 @@ macro_rules! prim_read_test {
     };
 }
 
+macro_rules! prim_write_test {
+    ($t: ident) => {
+        paste! {
+            #[test]
+            fn [<test_write_ $t>]() {
+                let mut rng = rand::thread_rng();
+                let num: $t = rng.gen();
+
+                let p = FakeProccess::new(
+                    vec![0u8; std::mem::size_of::<$t>() + 4]
+                );
+
+                p.[<write_ $t>](4, num).unwrap();
+
+                let tmp = p.[<read_ $t>](4).unwrap();
+                assert_eq!(tmp, num);
+            }
+        }
+    };
+}
+
 macro_rules! prim_read_array_test {
     ($t: ident) => {
         paste! {
@@ -74,9 +102,7 @@ macro_rules! prim_read_array_test {
                     synthetic_buff.extend(item.to_le_bytes())
                 }
 
-                let p = FakeProccess {
-                    buff: synthetic_buff
-                };
+                let p = FakeProccess::new(synthetic_buff);
 
                 let mut fake_output_buff = Vec::new();
 
@@ -90,8 +116,42 @@ macro_rules! prim_read_array_test {
     };
 }
 
+/// Readable and writable anonymous region
+fn fake_region(from: usize, size: usize) -> MemoryRegion {
+    MemoryRegion {
+        from,
+        size,
+        protection: Protection::new(true, true, false),
+        ..Default::default()
+    }
+}
+
 pub struct FakeProccess {
-    buff: Vec<u8>,
+    buff: RwLock<Vec<u8>>,
+    maps: Vec<MemoryRegion>,
+    arch: TargetArch,
+}
+
+impl FakeProccess {
+    /// Whole buffer is treated as one memory region
+    fn new(buff: Vec<u8>) -> Self {
+        let maps = vec![fake_region(0, buff.len())];
+
+        Self::with_regions(buff, maps)
+    }
+
+    fn with_regions(buff: Vec<u8>, maps: Vec<MemoryRegion>) -> Self {
+        Self {
+            buff: RwLock::new(buff),
+            maps,
+            arch: TargetArch::X86,
+        }
+    }
+
+    fn with_arch(mut self, arch: TargetArch) -> Self {
+        self.arch = arch;
+        self
+    }
 }
 
 impl ProcessTraits for FakeProccess {
@@ -113,11 +173,12 @@ impl ProcessTraits for FakeProccess {
         todo!()
     }
 
-    fn read_signature<T: TryFrom<usize>>(
-        &self,
-        _sign: &rosu_mem::signature::Signature,
-    ) -> Result<T, ProcessError> {
-        todo!()
+    fn regions(&self) -> &[MemoryRegion] {
+        &self.maps
+    }
+
+    fn arch(&self) -> TargetArch {
+        self.arch
     }
 
     fn read<T: TryInto<usize>>(
@@ -133,11 +194,30 @@ impl ProcessTraits for FakeProccess {
         // self.buff.set_position(addr as u64);
         // self.buff.read(buff);
 
-        let mut slice = &self.buff[addr..addr + len];
+        let fake_buff = self.buff.read().unwrap();
+
+        let Some(mut slice) = fake_buff.get(addr..addr + len) else {
+            return Err(ProcessError::BadAddress(addr, len));
+        };
         let _ = slice.read(buff);
 
         Ok(())
     }
+
+    fn write<T: TryInto<usize>>(
+        &self,
+        addr: T,
+        buff: &[u8],
+    ) -> Result<(), ProcessError> {
+        let addr: usize = addr
+            .try_into()
+            .map_err(|_| ProcessError::AddressConvertError)?;
+
+        self.buff.write().unwrap()[addr..addr + buff.len()]
+            .copy_from_slice(buff);
+
+        Ok(())
+    }
 }
 
 #[test]
@@ -146,7 +226,7 @@ fn test_uleb() {
         0x0B, 0x04, 0x74, 0x65, 0x73, 0x74, 0x00, 0x00, 0x00, 0x00, 0x0A,
     ];
 
-    let p = FakeProccess { buff };
+    let p = FakeProccess::new(buff);
 
     // Skipping that one 0x0b byte
     assert_eq!(p.read_uleb128(1).unwrap(), 4);
@@ -169,7 +249,7 @@ fn test_string() {
 
         buff.extend(random_string_bytes);
 
-        let p = FakeProccess { buff };
+        let p = FakeProccess::new(buff);
 
         let read_string = p.read_string(0).unwrap();
         assert_eq!(read_string, random_string);
@@ -193,13 +273,139 @@ fn test_string_64bitness() {
 
         buff.extend(random_string_bytes);
 
-        let p = FakeProccess { buff };
+        let p = FakeProccess::new(buff).with_arch(TargetArch::X64);
 
-        let read_string = p.read_string(0i64).unwrap();
+        let read_string = p.read_string(0).unwrap();
         assert_eq!(read_string, random_string);
     }
 }
 
+/// Builds C# string at the offset 0 of x86 process
+fn fake_string(units: &[u16]) -> Vec<u8> {
+    let mut buff = vec![0xCC; 4];
+    buff.extend_from_slice(&(units.len() as u32).to_le_bytes());
+    buff.extend(units.iter().flat_map(|unit| unit.to_le_bytes()));
+
+    buff
+}
+
+#[test]
+fn test_string_into() {
+    // Surrogate pair crosses the boundary of internal 512 units chunks
+    let string = format!("{}\u{1F600}{}", "a".repeat(511), "b".repeat(600));
+    let units: Vec<u16> = string.encode_utf16().collect();
+
+    let p = FakeProccess::new(fake_string(&units));
+
+    let mut buff = String::from("leftover");
+    p.read_string_into(0, &mut buff).unwrap();
+    assert_eq!(buff, string);
+
+    let mut raw = vec![1, 2, 3];
+    p.read_utf16_into(0, &mut raw).unwrap();
+    assert_eq!(raw, units);
+
+    // Unpaired surrogates are replaced only while decoding
+    let units = [0x61, 0xD800, 0x62, 0xDC00];
+    let p = FakeProccess::new(fake_string(&units));
+
+    p.read_string_into(0, &mut buff).unwrap();
+    assert_eq!(buff, "a\u{FFFD}b\u{FFFD}");
+    assert_eq!(buff, String::from_utf16_lossy(&units));
+    assert_eq!(buff, p.read_string(0).unwrap());
+
+    p.read_utf16_into(0, &mut raw).unwrap();
+    assert_eq!(raw, units);
+
+    let p = FakeProccess::new(fake_string(&[]));
+    p.read_string_into(0, &mut buff).unwrap();
+    assert!(buff.is_empty());
+}
+
+#[test]
+fn test_string_changed() {
+    use rosu_mem::process::StringFingerprint;
+
+    let units: Vec<u16> = "title".encode_utf16().collect();
+    let p = FakeProccess::new(fake_string(&units));
+
+    let mut fingerprint = StringFingerprint::default();
+    assert!(p.string_changed(0, &mut fingerprint).unwrap());
+    assert!(!p.string_changed(0, &mut fingerprint).unwrap());
+    assert_eq!(fingerprint.as_utf16(), units);
+
+    let mut title = String::new();
+    fingerprint.decode_into(&mut title);
+    assert_eq!(title, "title");
+
+    // Same length, different contents
+    p.write_u16(8, b'T' as u16).unwrap();
+    assert!(p.string_changed(0, &mut fingerprint).unwrap());
+    assert!(!p.string_changed(0, &mut fingerprint).unwrap());
+
+    // Different length
+    p.write_u32(4, 3u32).unwrap();
+    assert!(p.string_changed(0, &mut fingerprint).unwrap());
+    assert!(!p.string_changed(0, &mut fingerprint).unwrap());
+
+    fingerprint.decode_into(&mut title);
+    assert_eq!(title, "Tit");
+
+    // Failed read of a longer string doesn't keep the old fingerprint
+    p.write_u32(4, 0xFFFFu32).unwrap();
+    assert!(p.string_changed(0, &mut fingerprint).is_err());
+    p.write_u32(4, 3u32).unwrap();
+    assert!(p.string_changed(0, &mut fingerprint).unwrap());
+
+    // Empty string is still a change from the default
+    let p = FakeProccess::new(fake_string(&[]));
+    let mut fingerprint = StringFingerprint::default();
+    assert!(p.string_changed(0, &mut fingerprint).unwrap());
+}
+
+/// Layout is decided by the process architecture, not by the type of
+/// passed address
+#[test]
+fn test_arch_layouts() {
+    // | 08 00 00 00 00 00 00 00 | MT | 02 00 00 00 | 61 00 62 00 |
+    // ^ pointer to string      ^ string on x64
+    let mut buff = vec![0u8; 8];
+    buff[0] = 8;
+    buff.extend_from_slice(&[0xCC; 8]);
+    buff.extend_from_slice(&2u32.to_le_bytes());
+    buff.extend_from_slice(&[0x61, 0, 0x62, 0]);
+
+    let p = FakeProccess::new(buff.clone()).with_arch(TargetArch::X64);
+    assert_eq!(p.read_string_from_ptr(0i32).unwrap(), "ab");
+    assert_eq!(p.read_string_from_ptr(0u64).unwrap(), "ab");
+    assert_eq!(p.read_string(8i32).unwrap(), "ab");
+
+    // Same bytes on x86, header is 4 bytes, so string starts at 12 and
+    // reading from 8 gives 0xCCCCCCCC length
+    let p = FakeProccess::new(buff);
+    assert_eq!(p.read_string(12u64).unwrap(), "ab");
+    assert!(matches!(
+        p.read_string_with_limit(8i64, 16),
+        Err(ProcessError::StringTooLarge)
+    ));
+
+    // `List<f32>` on x64: `_items` at 0x8, `_size` at 0x10, backing
+    // array at 0x20 with data at 0x30
+    let mut buff = vec![0u8; 0x30];
+    buff[0x8] = 0x20;
+    buff[0x10] = 2;
+    buff[0x28] = 3;
+    for value in [1.5f32, -2.0, 7.0] {
+        buff.extend_from_slice(&value.to_le_bytes());
+    }
+
+    let p = FakeProccess::new(buff).with_arch(TargetArch::X64);
+
+    let mut values = Vec::new();
+    p.read_f32_array(0i32, &mut values).unwrap();
+    assert_eq!(values, [1.5, -2.0]);
+}
+
 #[test]
 fn test_string_with_limit_fail_all() {
     let mut rng = thread_rng();
@@ -217,7 +423,7 @@ fn test_string_with_limit_fail_all() {
 
         buff.extend(random_string_bytes);
 
-        let p = FakeProccess { buff };
+        let p = FakeProccess::new(buff);
 
         let read_string = p.read_string_with_limit(0, 8);
         assert!(read_string.is_err());
@@ -242,7 +448,7 @@ fn test_string_with_limit_no_fail() {
 
         buff.extend(random_string_bytes);
 
-        let p = FakeProccess { buff };
+        let p = FakeProccess::new(buff);
 
         let read_string = p.read_string_with_limit(0, 2048);
         assert!(read_string.is_ok());
@@ -277,7 +483,7 @@ fn test_string_from_ptr_simple() {
 
     buff.extend(random_string_bytes);
 
-    let p = FakeProccess { buff };
+    let p = FakeProccess::new(buff);
 
     let read_string = p.read_string_from_ptr(0).unwrap();
     assert_eq!(read_string, random_string);
@@ -320,7 +526,7 @@ fn test_string_from_ptr_randomized() {
             (pointer_points_at + 4 + 4 + (string_len as i32 * 2)) as usize
         );
 
-        let p = FakeProccess { buff };
+        let p = FakeProccess::new(buff);
 
         let read_string = p.read_string_from_ptr(0).unwrap();
         assert_eq!(read_string, random_string);
@@ -366,7 +572,7 @@ fn test_string_from_ptr_with_limit_randomized() {
             (pointer_points_at + 4 + 4 + (string_len as i32 * 2)) as usize
         );
 
-        let p = FakeProccess { buff };
+        let p = FakeProccess::new(buff);
 
         let read_string_res =
             p.read_string_with_limit_from_ptr(0, STRING_LIMIT);
@@ -383,6 +589,1092 @@ fn test_string_from_ptr_with_limit_randomized() {
     }
 }
 
+#[test]
+fn test_pointer_chain() {
+    // | 10 00 00 00 00 00 00 00 | ...
+    // ^ [0] -> 0x10
+    // | 0x10: 00 00 00 00 | 20 00 00 00 00 00 00 00 | ...
+    //                     ^ [0x14] -> 0x20
+    let mut buff = vec![0u8; 0x30];
+    buff[0..8].copy_from_slice(&0x10u64.to_le_bytes());
+    buff[0x14..0x1C].copy_from_slice(&0x20u64.to_le_bytes());
+    buff[0x28..0x30].copy_from_slice(&1337u64.to_le_bytes());
+
+    let p = FakeProccess::new(buff);
+
+    let chain = PointerChain::new(0x8usize, PointerWidth::X64)
+        .offset(-0x8)
+        .offset(0x4)
+        .offset(0x8);
+
+    assert_eq!(p.resolve_chain(&chain).unwrap(), 1337);
+
+    let mut buff = vec![0u8; 0x10];
+    buff[0..4].copy_from_slice(&0x8u32.to_le_bytes());
+    buff[0xC..0x10].copy_from_slice(&42u32.to_le_bytes());
+
+    let p = FakeProccess::new(buff);
+
+    let chain = PointerChain::new(0usize, PointerWidth::X32)
+        .offset(0)
+        .offset(0x4);
+
+    assert_eq!(p.resolve_chain(&chain).unwrap(), 42);
+}
+
+#[test]
+fn test_pointer_chain_broken_hop() {
+    let mut buff = vec![0u8; 0x10];
+    buff[0..4].copy_from_slice(&0x8u32.to_le_bytes());
+
+    let p = FakeProccess::new(buff);
+
+    let chain = PointerChain::new(0usize, PointerWidth::X32)
+        .offset(0)
+        .offset(0x1000);
+
+    let res = p.resolve_chain(&chain);
+
+    assert!(matches!(
+        res,
+        Err(ProcessError::BrokenPointerChain {
+            hop: 1,
+            addr: 0x1008,
+            ..
+        })
+    ));
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct FakeHitObject {
+    x: f32,
+    y: f32,
+    start_time: i32,
+    end_time: i32,
+    kind: u8,
+    _pad: [u8; 3],
+}
+
+unsafe impl Pod for FakeHitObject {}
+
+impl FakeHitObject {
+    fn random(rng: &mut impl Rng) -> Self {
+        Self {
+            x: rng.gen(),
+            y: rng.gen(),
+            start_time: rng.gen(),
+            end_time: rng.gen(),
+            kind: rng.gen(),
+            _pad: [0; 3],
+        }
+    }
+
+    fn to_bytes(self) -> Vec<u8> {
+        let mut buff = Vec::new();
+        buff.extend(self.x.to_le_bytes());
+        buff.extend(self.y.to_le_bytes());
+        buff.extend(self.start_time.to_le_bytes());
+        buff.extend(self.end_time.to_le_bytes());
+        buff.push(self.kind);
+        buff.extend(self._pad);
+
+        assert_eq!(buff.len(), std::mem::size_of::<Self>());
+
+        buff
+    }
+}
+
+#[test]
+fn test_pod() {
+    let mut rng = thread_rng();
+    let object = FakeHitObject::random(&mut rng);
+
+    let mut buff = vec![0u8; 4];
+    buff.extend(object.to_bytes());
+
+    let p = FakeProccess::new(buff);
+
+    let read_object: FakeHitObject = p.read_pod(4).unwrap();
+    assert_eq!(read_object, object);
+
+    let read_array: [u16; 2] = p.read_pod(0).unwrap();
+    assert_eq!(read_array, [0, 0]);
+}
+
+#[test]
+fn test_pod_array() {
+    let mut rng = thread_rng();
+
+    let objects: Vec<FakeHitObject> = (0..rng.gen_range(0..128))
+        .map(|_| FakeHitObject::random(&mut rng))
+        .collect();
+
+    let buff = objects
+        .iter()
+        .flat_map(|object| object.to_bytes())
+        .collect();
+
+    let p = FakeProccess::new(buff);
+
+    let mut read_objects = vec![FakeHitObject::random(&mut rng)];
+    p.read_pod_array(0, objects.len(), &mut read_objects)
+        .unwrap();
+
+    assert_eq!(read_objects, objects);
+}
+
+/// Builds CLR `List<T>` at the offset 0 with its backing array at
+/// 0x40, `capacity` elements are allocated but only `size` are used
+fn fake_list(
+    width: PointerWidth,
+    items: &[u8],
+    item_size: usize,
+    size: usize,
+) -> Vec<u8> {
+    let ptr = width.size();
+    let mut buff = vec![0u8; 0x40];
+
+    let (items_offset, size_offset) = match width {
+        PointerWidth::X32 => (0x4, 0xC),
+        PointerWidth::X64 => (0x8, 0x10),
+    };
+
+    buff[items_offset..items_offset + ptr]
+        .copy_from_slice(&0x40u64.to_le_bytes()[..ptr]);
+    buff[size_offset..size_offset + 4]
+        .copy_from_slice(&(size as u32).to_le_bytes());
+
+    // Array header: method table and padded length
+    buff.extend_from_slice(&vec![0xCC; ptr]);
+    let mut length = vec![0u8; ptr];
+    length[..4]
+        .copy_from_slice(&((items.len() / item_size) as u32).to_le_bytes());
+    buff.extend_from_slice(&length);
+    buff.extend_from_slice(items);
+
+    buff
+}
+
+#[test]
+fn test_list() {
+    let values: Vec<i32> = vec![1, -2, 3, 4, 0x7777, 0x7777];
+    let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+
+    for width in [PointerWidth::X32, PointerWidth::X64] {
+        let p = FakeProccess::new(fake_list(width, &bytes, 4, 4));
+
+        let mut buff: Vec<i32> = vec![0xFF];
+        p.read_list_with_width(0, width, &mut buff).unwrap();
+        assert_eq!(buff, [1, -2, 3, 4]);
+
+        // Size over the array length
+        let p = FakeProccess::new(fake_list(width, &bytes, 4, 7))
+            .with_arch(width.into());
+        assert!(matches!(
+            p.read_list(0, &mut buff),
+            Err(ProcessError::InvalidCollectionSize {
+                size: 7,
+                capacity: 6
+            })
+        ));
+        assert!(matches!(
+            p.read_i32_array(0, &mut buff),
+            Err(ProcessError::InvalidCollectionSize { .. })
+        ));
+
+        // Empty list doesn't touch items pointer at all
+        let mut buff = fake_list(width, &bytes, 4, 0);
+        buff[..0x20].fill(0);
+        let p = FakeProccess::new(buff);
+
+        let mut values: Vec<i32> = vec![5];
+        p.read_list_with_width(0, width, &mut values).unwrap();
+        assert!(values.is_empty());
+    }
+}
+
+#[test]
+fn test_list_of_ptrs() {
+    let ptrs: [u64; 3] = [0x1000, 0xDEAD_BEEF, 0x10];
+
+    let bytes: Vec<u8> = ptrs
+        .iter()
+        .flat_map(|v| (*v as u32).to_le_bytes())
+        .collect();
+    let p = FakeProccess::new(fake_list(PointerWidth::X32, &bytes, 4, 2));
+
+    let mut buff = Vec::new();
+    p.read_list_of_ptrs_with_width(0, PointerWidth::X32, &mut buff)
+        .unwrap();
+    assert_eq!(buff, [0x1000, 0xDEAD_BEEF]);
+
+    // Layout follows the process arch by default
+    let p = p.with_arch(TargetArch::X86);
+    p.read_list_of_ptrs(0, &mut buff).unwrap();
+    assert_eq!(buff, [0x1000, 0xDEAD_BEEF]);
+
+    let bytes: Vec<u8> = ptrs.iter().flat_map(|v| v.to_le_bytes()).collect();
+    let p = FakeProccess::new(fake_list(PointerWidth::X64, &bytes, 8, 3));
+
+    p.read_list_of_ptrs_with_width(0, PointerWidth::X64, &mut buff)
+        .unwrap();
+    assert_eq!(buff, [0x1000, 0xDEAD_BEEF, 0x10]);
+}
+
+/// Parses space separated hex bytes of a memory dump
+fn hex(dump: &str) -> Vec<u8> {
+    dump.split_whitespace()
+        .map(|byte| u8::from_str_radix(byte, 16).unwrap())
+        .collect()
+}
+
+/// `List<int>` of .NET Framework (osu! stable) written byte by byte,
+/// so offsets don't come from `fake_list()`. Objects are separated by
+/// 4B object header of the next one
+#[test]
+fn test_list_layout_x86() {
+    let buff = hex("
+        10 20 30 40  18 00 00 00  00 00 00 00  03 00 00 00
+        07 00 00 00  00 00 00 00  A0 B0 C0 D0  04 00 00 00
+        01 00 00 00  FE FF FF FF  03 00 00 00  00 00 00 00
+    ");
+    // 0x00 list:  MT | _items | _syncRoot | _size = 3 | _version
+    // 0x18 int[]: MT | length = 4 | 1, -2, 3, 0
+
+    let p = FakeProccess::new(buff).with_arch(TargetArch::X86);
+
+    let mut values: Vec<i32> = Vec::new();
+    p.read_list(0, &mut values).unwrap();
+    assert_eq!(values, [1, -2, 3]);
+}
+
+/// `List<int>` of .NET (osu! lazer) written byte by byte. Objects are
+/// separated by 8B object header of the next one
+#[test]
+fn test_list_layout_x64() {
+    let buff = hex("
+        10 20 30 40 50 60 70 00  20 00 00 00 00 00 00 00
+        03 00 00 00 07 00 00 00  00 00 00 00 00 00 00 00
+        A0 B0 C0 D0 E0 F0 00 00  04 00 00 00 00 00 00 00
+        01 00 00 00 FE FF FF FF  03 00 00 00 00 00 00 00
+    ");
+    // 0x00 list:  MT | _items | _size = 3 | _version
+    // 0x20 int[]: MT | length = 4 + padding | 1, -2, 3, 0
+
+    let p = FakeProccess::new(buff).with_arch(TargetArch::X64);
+
+    let mut values: Vec<i32> = Vec::new();
+    p.read_list(0, &mut values).unwrap();
+    assert_eq!(values, [1, -2, 3]);
+}
+
+#[test]
+fn test_array() {
+    // `float[]` on x64: method table, padded length and data at 0x10
+    let mut buff = vec![0xCC; 8];
+    buff.extend_from_slice(&3u64.to_le_bytes());
+    for value in [0.5f32, 1.0, -4.25] {
+        buff.extend_from_slice(&value.to_le_bytes());
+    }
+
+    let p = FakeProccess::new(buff).with_arch(TargetArch::X64);
+
+    let mut values: Vec<f32> = vec![9.0; 8];
+    p.read_array(0, &mut values).unwrap();
+    assert_eq!(values, [0.5, 1.0, -4.25]);
+
+    p.read_array_with_limit(0, 3, &mut values).unwrap();
+    assert_eq!(values, [0.5, 1.0, -4.25]);
+
+    let res = p.read_array_with_limit(0, 2, &mut values);
+    assert!(matches!(res, Err(ProcessError::ArrayTooLarge(3))));
+
+    // `int[]` on x86: method table, length and data at 0x8
+    let mut buff = vec![0xCC; 4];
+    buff.extend_from_slice(&2u32.to_le_bytes());
+    buff.extend_from_slice(&7i32.to_le_bytes());
+    buff.extend_from_slice(&(-7i32).to_le_bytes());
+
+    let p = FakeProccess::new(buff);
+
+    let mut values: Vec<i32> = Vec::new();
+    p.read_array(0, &mut values).unwrap();
+    assert_eq!(values, [7, -7]);
+
+    // Length is over the buffer
+    let p = p.with_arch(TargetArch::X64);
+    let res = p.read_array(0, &mut values);
+    assert!(matches!(res, Err(ProcessError::BadAddress(..))));
+}
+
+#[test]
+fn test_ptr_array() {
+    let ptrs: [u64; 3] = [0x1000, 0xDEAD_BEEF, 0];
+
+    for arch in [TargetArch::X86, TargetArch::X64] {
+        let size = arch.ptr_size();
+
+        let mut buff = vec![0xCC; size];
+        buff.extend_from_slice(&3u64.to_le_bytes()[..size]);
+        for ptr in ptrs {
+            buff.extend_from_slice(&ptr.to_le_bytes()[..size]);
+        }
+
+        let p = FakeProccess::new(buff).with_arch(arch);
+
+        let mut addrs = vec![1];
+        p.read_ptr_array(0, &mut addrs).unwrap();
+        assert_eq!(addrs, [0x1000, 0xDEAD_BEEF, 0]);
+
+        let res = p.read_ptr_array_with_limit(0, 1, &mut addrs);
+        assert!(matches!(res, Err(ProcessError::ArrayTooLarge(3))));
+    }
+}
+
+/// Builds CLR `Dictionary` at the offset 0 with its entries array at
+/// 0x60, entries are `(key, value, hashCode, next)` with keys and
+/// values being references
+fn fake_dictionary(
+    width: PointerWidth,
+    entries: &[(u64, u64, i32, i32)],
+    count: usize,
+) -> Vec<u8> {
+    let ptr = width.size();
+    let mut buff = vec![0u8; 0x60];
+
+    let (entries_offset, count_offset) = match width {
+        PointerWidth::X32 => (0x8, 0x1C),
+        PointerWidth::X64 => (0x10, 0x38),
+    };
+
+    buff[entries_offset..entries_offset + ptr]
+        .copy_from_slice(&0x60u64.to_le_bytes()[..ptr]);
+    buff[count_offset..count_offset + 4]
+        .copy_from_slice(&(count as u32).to_le_bytes());
+
+    // Array header: method table and padded length
+    buff.extend_from_slice(&vec![0xCC; ptr]);
+    let mut length = vec![0u8; ptr];
+    length[..4].copy_from_slice(&(entries.len() as u32).to_le_bytes());
+    buff.extend_from_slice(&length);
+
+    for (key, value, hash_code, next) in entries {
+        buff.extend_from_slice(&key.to_le_bytes()[..ptr]);
+        buff.extend_from_slice(&value.to_le_bytes()[..ptr]);
+        buff.extend_from_slice(&hash_code.to_le_bytes());
+        buff.extend_from_slice(&next.to_le_bytes());
+    }
+
+    buff
+}
+
+/// `Dictionary<string, object>` of .NET Framework (osu! stable)
+/// written byte by byte, so offsets don't come from
+/// `fake_dictionary()`. Second entry is removed
+#[test]
+fn test_dictionary_layout_x86() {
+    use rosu_mem::process::DictionaryEntry;
+
+    let buff = hex("
+        10 20 30 40  00 01 00 00  30 00 00 00  00 02 00 00
+        00 03 00 00  00 04 00 00  00 00 00 00  03 00 00 00
+        05 00 00 00  01 00 00 00  01 00 00 00  00 00 00 00
+        A0 B0 C0 D0  03 00 00 00  00 10 00 00  00 20 00 00
+        0D F0 AD 0B  FF FF FF FF  00 00 00 00  00 00 00 00
+        FF FF FF FF  FF FF FF FF  10 10 00 00  10 20 00 00
+        34 12 00 00  00 00 00 00
+    ");
+    // 0x00 dictionary: MT | _buckets | _entries | _comparer | _keys
+    //                  | _values | _syncRoot | _count = 3 | _version
+    //                  | _freeList = 1 | _freeCount = 1
+    // 0x30 Entry[]:    MT | length = 3 | entries of 0x10 bytes:
+    //                  key | value | hashCode | next
+
+    let p = FakeProccess::new(buff).with_arch(TargetArch::X86);
+    let width = PointerWidth::X32;
+
+    let mut buff = Vec::new();
+    p.read_dictionary(
+        0,
+        DictionaryEntry::references(width),
+        |p, addr| p.read_ptr(addr, width),
+        |p, addr| p.read_ptr(addr, width),
+        &mut buff,
+    )
+    .unwrap();
+
+    assert_eq!(buff, [(0x1000, 0x2000), (0x1010, 0x2010)]);
+}
+
+/// `Dictionary<string, object>` of .NET (osu! lazer) written byte by
+/// byte. Second entry is removed, first one has high bit of
+/// `hashCode` set
+#[test]
+fn test_dictionary_layout_x64() {
+    use rosu_mem::process::DictionaryEntry;
+
+    let buff = hex("
+        10 20 30 40 50 60 70 00  00 01 00 00 00 00 00 00
+        50 00 00 00 00 00 00 00  00 02 00 00 00 00 00 00
+        00 03 00 00 00 00 00 00  00 04 00 00 00 00 00 00
+        AB AB AB AB AB AB AB AB  03 00 00 00 01 00 00 00
+        01 00 00 00 05 00 00 00  00 00 00 00 00 00 00 00
+        A0 B0 C0 D0 E0 F0 00 00  03 00 00 00 00 00 00 00
+        00 10 00 00 00 00 00 00  00 20 00 00 00 00 00 00
+        01 00 00 80 FF FF FF FF  00 00 00 00 00 00 00 00
+        00 00 00 00 00 00 00 00  00 00 00 00 FE FF FF FF
+        10 10 00 00 00 00 00 00  10 20 00 00 00 00 00 00
+        34 12 00 00 00 00 00 00
+    ");
+    // 0x00 dictionary: MT | _buckets | _entries | _comparer | _keys
+    //                  | _values | _fastModMultiplier | _count = 3
+    //                  | _freeList = 1 | _freeCount = 1 | _version
+    // 0x50 Entry[]:    MT | length = 3 + padding | entries of 0x18
+    //                  bytes: key | value | hashCode | next, removed
+    //                  entry has `next` of `-3 - next_free`
+
+    let p = FakeProccess::new(buff).with_arch(TargetArch::X64);
+    let width = PointerWidth::X64;
+
+    let mut buff = Vec::new();
+    p.read_dictionary(
+        0,
+        DictionaryEntry::references(width),
+        |p, addr| p.read_ptr(addr, width),
+        |p, addr| p.read_ptr(addr, width),
+        &mut buff,
+    )
+    .unwrap();
+
+    assert_eq!(buff, [(0x1000, 0x2000), (0x1010, 0x2010)]);
+}
+
+#[test]
+fn test_dictionary() {
+    use rosu_mem::process::DictionaryEntry;
+
+    // Removed entry is the start of the free list. .NET Framework
+    // (osu! stable) marks it with `hashCode` of -1 and keeps `next`
+    // pointing to the previous free entry, .NET (osu! lazer) encodes
+    // `next` as `-3 - next_free` instead
+    let removed = |width| match width {
+        PointerWidth::X32 => (0, 0, -1, -1),
+        PointerWidth::X64 => (0, 0, 0, -3),
+    };
+
+    // .NET keeps the high bit of `hashCode`, Framework masks it out
+    let hash_code = |width| match width {
+        PointerWidth::X32 => 0x1234,
+        PointerWidth::X64 => 0x8000_0001u32 as i32,
+    };
+
+    for width in [PointerWidth::X32, PointerWidth::X64] {
+        let entries = [
+            (0x10, 0x100, 0x1234, -1),
+            removed(width),
+            (0x20, 0x200, hash_code(width), 0),
+            (0x30, 0x300, 0x1234, -1),
+            // Past `_count`, never used
+            (0x40, 0x400, 0x1234, -1),
+        ];
+
+        let p = FakeProccess::new(fake_dictionary(width, &entries, 4))
+            .with_arch(width.into());
+        let entry = DictionaryEntry::references(width);
+
+        // Null keys fail like `read_string_from_ptr()` does
+        let read_key = |p: &FakeProccess, addr| match p.read_ptr(addr, width)? {
+            0 => Err(ProcessError::BadAddress(0, 4)),
+            key => Ok(key),
+        };
+
+        // Layout follows the process arch by default
+        let mut buff = vec![(1, 1)];
+        p.read_dictionary(
+            0,
+            entry,
+            read_key,
+            |p, addr| p.read_ptr(addr, width),
+            &mut buff,
+        )
+        .unwrap();
+
+        assert_eq!(buff, [(0x10, 0x100), (0x20, 0x200), (0x30, 0x300)]);
+
+        // Errors of readers are propagated
+        let res = p.read_dictionary_with_width(
+            0,
+            width,
+            entry,
+            |p, addr| p.read_ptr(addr, width),
+            |_, _| Err::<(), _>(ProcessError::BadAddress(0, 0)),
+            &mut Vec::new(),
+        );
+        assert!(matches!(res, Err(ProcessError::BadAddress(0, 0))));
+
+        let p = FakeProccess::new(fake_dictionary(width, &entries, 6));
+        let res = p.read_dictionary_with_width(
+            0,
+            width,
+            entry,
+            |p, addr| p.read_u32(addr),
+            |p, addr| p.read_u32(addr),
+            &mut Vec::new(),
+        );
+        assert!(matches!(
+            res,
+            Err(ProcessError::InvalidCollectionSize {
+                size: 6,
+                capacity: 5
+            })
+        ));
+    }
+}
+
+#[test]
+fn test_signature_all() {
+    //              0     1     2     3     4     5     6     7
+    let buff = vec![0xAB, 0x30, 0xFF, 0x30, 0x00, 0xAB, 0x30, 0xFF];
+
+    let maps = vec![fake_region(0, 4), fake_region(4, 4)];
+
+    let p = FakeProccess::with_regions(buff, maps);
+
+    let sign = Signature::from_str("AB 30 FF").unwrap();
+
+    let found: Vec<usize> = p.read_signature_all(&sign).unwrap();
+    assert_eq!(found, [0, 5]);
+
+    let first: usize = p.read_signature(&sign).unwrap();
+    assert_eq!(first, 0);
+
+    let res = p.read_signature_unique::<usize>(&sign);
+    assert!(matches!(res, Err(ProcessError::SignatureAmbiguous(2))));
+
+    let sign = Signature::from_str("00 AB").unwrap();
+    let unique: usize = p.read_signature_unique(&sign).unwrap();
+    assert_eq!(unique, 4);
+
+    let sign = Signature::from_str("CB CB").unwrap();
+    let res = p.read_signature_unique::<usize>(&sign);
+    assert!(matches!(res, Err(ProcessError::SignatureNotFound(_))));
+    assert!(p.read_signature_all::<usize>(&sign).unwrap().is_empty());
+}
+
+#[test]
+fn test_signature_across_regions() {
+    //              0     1     2     3     4     5     6     7
+    let buff = vec![0x00, 0x00, 0xAB, 0x30, 0xFF, 0x10, 0x00, 0x00];
+
+    let sign = Signature::from_str("AB 30 FF 10").unwrap();
+
+    // Pattern split in the middle
+    let maps = vec![fake_region(0, 4), fake_region(4, 4)];
+    let p = FakeProccess::with_regions(buff.clone(), maps);
+
+    let found: usize = p.read_signature(&sign).unwrap();
+    assert_eq!(found, 2);
+
+    let found: Vec<usize> = p.read_signature_all(&sign).unwrap();
+    assert_eq!(found, [2]);
+
+    // Pattern spans three regions
+    let maps = vec![fake_region(0, 3), fake_region(3, 1), fake_region(4, 4)];
+    let p = FakeProccess::with_regions(buff.clone(), maps);
+
+    let found: Vec<usize> = p.read_signature_all(&sign).unwrap();
+    assert_eq!(found, [2]);
+
+    // Regions are not adjacent, so nothing should be found
+    let maps = vec![fake_region(0, 4), fake_region(5, 3)];
+    let p = FakeProccess::with_regions(buff, maps);
+
+    let found: Vec<usize> = p.read_signature_all(&sign).unwrap();
+    assert!(found.is_empty());
+}
+
+#[test]
+fn test_signature_filtered() {
+    //              0     1     2     3     4     5     6     7
+    let buff = vec![0xAB, 0x30, 0xFF, 0x00, 0xAB, 0x30, 0xFF, 0x00];
+
+    let maps = vec![
+        MemoryRegion {
+            from: 0,
+            size: 4,
+            protection: Protection::new(true, false, true),
+            kind: RegionKind::File,
+            path: Some("/osu!/osu!.exe".into()),
+        },
+        fake_region(4, 4),
+    ];
+
+    let p = FakeProccess::with_regions(buff, maps);
+
+    let sign = Signature::from_str("AB 30 FF").unwrap();
+
+    // Default filter skips read-only code everywhere except windows
+    let found: Vec<usize> = p.read_signature_all(&sign).unwrap();
+    if cfg!(target_os = "windows") {
+        assert_eq!(found, [0, 4]);
+    } else {
+        assert_eq!(found, [4]);
+    }
+
+    let filter = RegionFilter::any().executable(true);
+    let found: usize = p.read_signature_filtered(&sign, &filter).unwrap();
+    assert_eq!(found, 0);
+
+    let filter = RegionFilter::any().path_contains("osu!.exe");
+    let found: usize =
+        p.read_signature_unique_filtered(&sign, &filter).unwrap();
+    assert_eq!(found, 0);
+
+    let filter = RegionFilter::any()
+        .kind(RegionKind::Heap)
+        .kind(RegionKind::Anonymous);
+    let found: Vec<usize> =
+        p.read_signature_all_filtered(&sign, &filter).unwrap();
+    assert_eq!(found, [4]);
+
+    let filter = RegionFilter::any();
+    let found: Vec<usize> =
+        p.read_signature_all_filtered(&sign, &filter).unwrap();
+    assert_eq!(found, [0, 4]);
+}
+
+/// Buffer with three adjacent regions, used for scanning
+/// several signatures at once
+fn fake_scan_process() -> FakeProccess {
+    let buff = vec![
+        0xAB, 0x30, 0xFF, 0x00, // 0..4
+        0x12, 0x34, 0x56, // 4..7
+        0x78, 0xAB, 0x30, 0xFF, 0x00, // 7..12
+    ];
+
+    let maps = vec![fake_region(0, 4), fake_region(4, 3), fake_region(7, 5)];
+
+    FakeProccess::with_regions(buff, maps)
+}
+
+#[test]
+fn test_scan_signatures() {
+    let p = fake_scan_process();
+
+    let first = Signature::from_str("AB 30 FF").unwrap();
+    let across = Signature::from_str("56 78 AB").unwrap();
+    let missing = Signature::from_str("CB CB").unwrap();
+    // Middle region is shorter than the signature
+    let spanning = Signature::from_str("FF 00 12 34 56 78 AB").unwrap();
+
+    let found: Vec<Option<usize>> = p
+        .scan_signatures(&[&first, &across, &missing, &spanning])
+        .unwrap();
+
+    assert_eq!(found, [Some(0), Some(6), None, Some(2)]);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_par_scan_signatures() {
+    let p = fake_scan_process();
+
+    let first = Signature::from_str("AB 30 FF").unwrap();
+    let across = Signature::from_str("56 78 AB").unwrap();
+    let missing = Signature::from_str("CB CB").unwrap();
+    // Middle region is shorter than the signature
+    let spanning = Signature::from_str("FF 00 12 34 56 78 AB").unwrap();
+
+    let found: Vec<Option<usize>> = p
+        .par_scan_signatures(
+            &[&first, &across, &missing, &spanning],
+            &RegionFilter::default(),
+        )
+        .unwrap();
+
+    assert_eq!(found, [Some(0), Some(6), None, Some(2)]);
+
+    let found: Vec<Option<usize>> = p
+        .par_scan_signatures(&[], &RegionFilter::default())
+        .unwrap();
+    assert!(found.is_empty());
+}
+
+#[test]
+fn test_read_signature_in() {
+    let mut buff = vec![0u8; 0x30];
+    buff[0x4..0x6].copy_from_slice(&[0xAB, 0xCD]);
+    buff[0x14..0x16].copy_from_slice(&[0xAB, 0xCD]);
+    buff[0x24..0x26].copy_from_slice(&[0xAB, 0xCD]);
+
+    let mut image = fake_region(0, 0x10);
+    image.protection = Protection::new(true, false, true);
+    image.kind = RegionKind::File;
+    image.path = Some("/games/osu/ClrJit.dll".into());
+
+    let maps = vec![image, fake_region(0x10, 0x10), fake_region(0x20, 0x10)];
+    let p = FakeProccess::with_regions(buff, maps);
+
+    let sign = Signature::from_str("AB CD").unwrap();
+
+    let addr: usize = p.read_signature(&sign).unwrap();
+    if cfg!(target_os = "windows") {
+        assert_eq!(addr, 0x4);
+    } else {
+        assert_eq!(addr, 0x14);
+    }
+
+    let addr: usize = p.read_signature_in(&sign, 0x0..0x30).unwrap();
+    assert_eq!(addr, 0x4);
+
+    let addr: usize = p.read_signature_in(&sign, 0x15..0x30).unwrap();
+    assert_eq!(addr, 0x24);
+
+    // Match crossing the end of the range
+    let res = p.read_signature_in::<usize>(&sign, 0x5..0x15);
+    assert!(matches!(res, Err(ProcessError::SignatureNotFound(_))));
+
+    let addr: usize = p.read_signature_in_module(&sign, "clrjit.dll").unwrap();
+    assert_eq!(addr, 0x4);
+
+    let res = p.read_signature_in_module::<usize>(&sign, "coreclr.dll");
+    assert!(matches!(res, Err(ProcessError::ModuleNotFound(_))));
+}
+
+#[test]
+fn test_modules() {
+    let file = |from, size, path: &str| {
+        let mut region = fake_region(from, size);
+        region.kind = RegionKind::File;
+        region.path = Some(path.into());
+        region
+    };
+
+    let mut heap = fake_region(0x10000, 0x1000);
+    heap.kind = RegionKind::Heap;
+
+    let maps = vec![
+        file(0x1000, 0x1000, "/games/osu/osu!.exe"),
+        file(0x2000, 0x2000, "/games/osu/osu!.exe"),
+        // Anonymous gap between sections, like wine maps them
+        fake_region(0x4000, 0x800),
+        file(0x4800, 0x800, "/games/osu/osu!.exe"),
+        fake_region(0x5000, 0x1000),
+        file(0x6000, 0x1000, "/usr/lib/libSDL2.so"),
+        file(0x8000, 0x1000, "/usr/lib/libSDL2.so"),
+        file(0x9000, 0x1000, "/usr/lib/libc.so.6"),
+        fake_region(0xA000, 0x1000),
+        file(0xB000, 0x1000, "/usr/lib/libSDL2.so"),
+        heap,
+        file(0x11000, 0x1000, "/usr/lib/libSDL2.so"),
+    ];
+
+    let p = FakeProccess::with_regions(Vec::new(), maps);
+
+    let modules: Vec<(String, usize, usize)> = p
+        .modules()
+        .into_iter()
+        .map(|m| (m.name, m.base, m.size))
+        .collect();
+
+    assert_eq!(
+        modules,
+        [
+            ("osu!.exe".to_owned(), 0x1000, 0x4000),
+            ("libSDL2.so".to_owned(), 0x6000, 0x3000),
+            ("libc.so.6".to_owned(), 0x9000, 0x1000),
+            ("libSDL2.so".to_owned(), 0xB000, 0x1000),
+            ("libSDL2.so".to_owned(), 0x11000, 0x1000),
+        ]
+    );
+
+    let module = p.module("OSU!.EXE").unwrap();
+    assert_eq!(module.range(), 0x1000..0x5000);
+    assert!(module.contains(0x4FFF));
+    assert!(!module.contains(0x5000));
+
+    assert!(p.module("clr.dll").is_none());
+}
+
+/// Minimal PE32 image with two sections and two exports
+fn fake_pe_image(buff: &mut [u8]) {
+    let mut put = |at: usize, bytes: &[u8]| {
+        buff[at..at + bytes.len()].copy_from_slice(bytes);
+    };
+
+    put(0x0, b"MZ");
+    put(0x3C, &0x80u32.to_le_bytes());
+
+    // NT headers
+    put(0x80, b"PE\0\0");
+    put(0x84, &0x14Cu16.to_le_bytes()); // machine
+    put(0x86, &2u16.to_le_bytes()); // sections count
+    put(0x94, &0xE0u16.to_le_bytes()); // optional header size
+
+    // Optional header
+    put(0x98, &0x10Bu16.to_le_bytes());
+    put(0x98 + 16, &0x210u32.to_le_bytes()); // entry point
+    put(0x98 + 28, &0x400000u32.to_le_bytes()); // image base
+    put(0x98 + 56, &0x1000u32.to_le_bytes()); // size of image
+    put(0x98 + 92, &16u32.to_le_bytes()); // directories count
+    put(0x98 + 96, &0x400u32.to_le_bytes()); // export directory
+    put(0x98 + 100, &0x100u32.to_le_bytes());
+
+    // Sections
+    put(0x178, b".text");
+    put(0x178 + 8, &0x100u32.to_le_bytes());
+    put(0x178 + 12, &0x200u32.to_le_bytes());
+    put(0x178 + 36, &0x6000_0020u32.to_le_bytes());
+    put(0x1A0, b".data");
+    put(0x1A0 + 8, &0x80u32.to_le_bytes());
+    put(0x1A0 + 12, &0x300u32.to_le_bytes());
+    put(0x1A0 + 36, &0xC000_0040u32.to_le_bytes());
+
+    // Export directory
+    put(0x400 + 16, &1u32.to_le_bytes()); // ordinal base
+    put(0x400 + 20, &2u32.to_le_bytes()); // functions
+    put(0x400 + 24, &2u32.to_le_bytes()); // names
+    put(0x400 + 28, &0x440u32.to_le_bytes());
+    put(0x400 + 32, &0x450u32.to_le_bytes());
+    put(0x400 + 36, &0x460u32.to_le_bytes());
+
+    put(0x440, &0x210u32.to_le_bytes());
+    put(0x444, &0x220u32.to_le_bytes());
+    put(0x450, &0x490u32.to_le_bytes());
+    put(0x454, &0x480u32.to_le_bytes());
+    put(0x460, &[1, 0, 0, 0]);
+    put(0x480, b"first\0");
+    put(0x490, b"second\0");
+}
+
+#[test]
+fn test_pe_image() {
+    use rosu_mem::pe::PeImage;
+
+    let mut buff = vec![0u8; 0x2000];
+    fake_pe_image(&mut buff[0x1000..]);
+
+    let p = FakeProccess::new(buff);
+
+    let image = PeImage::read(&p, 0x1000).unwrap();
+
+    assert_eq!(image.machine, 0x14C);
+    assert_eq!(image.width, PointerWidth::X32);
+    assert_eq!(image.image_base, 0x400000);
+    assert_eq!(image.range(), 0x1000..0x2000);
+    assert_eq!(image.entry_point, Some(0x1210));
+
+    let names: Vec<&str> =
+        image.sections.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, [".text", ".data"]);
+
+    let text = image.section(".text").unwrap();
+    assert_eq!(text.range(), 0x1200..0x1300);
+    assert!(text.is_executable());
+    assert!(!text.is_writable());
+
+    let data = image.section(".data").unwrap();
+    assert_eq!(data.range(), 0x1300..0x1380);
+    assert!(data.is_writable());
+
+    let exports = image.exports(&p).unwrap();
+    assert_eq!(exports.len(), 2);
+    assert_eq!(exports[0].name, "second");
+    assert_eq!(exports[0].ordinal, 2);
+    assert_eq!(exports[0].addr, 0x1220);
+
+    assert_eq!(image.export(&p, "first").unwrap(), Some(0x1210));
+    assert_eq!(image.export(&p, "third").unwrap(), None);
+
+    assert_eq!(TargetArch::from(image.width), TargetArch::X86);
+    assert_eq!(TargetArch::X86.width(), PointerWidth::X32);
+
+    let res = PeImage::read(&p, 0x1080);
+    assert!(matches!(res, Err(ProcessError::InvalidImage(_))));
+
+    // Garbage in the export directory
+    p.write_u32(0x1400 + 24, u32::MAX).unwrap();
+    let res = image.exports(&p);
+    assert!(matches!(res, Err(ProcessError::InvalidImage(_))));
+
+    p.write_u32(0x1400 + 24, 2).unwrap();
+    p.write_u32(0x1400 + 16, u32::MAX).unwrap();
+    let exports = image.exports(&p).unwrap();
+    assert_eq!(exports[0].ordinal, 0);
+}
+
+#[test]
+fn test_signature_capture() {
+    let mut buff = vec![0u8; 0x20];
+
+    // mov rax, [rip + 0x10]
+    buff[0x4..0xB].copy_from_slice(&[0x48, 0x8B, 0x05, 0x10, 0x00, 0x00, 0x00]);
+    // jmp -0x4
+    buff[0xB..0xD].copy_from_slice(&[0xEB, 0xFC]);
+
+    let p = FakeProccess::new(buff);
+
+    let sign = Signature::from_str("48 8B 05 [?? ?? ?? ??]").unwrap();
+
+    let addr: usize = p
+        .read_signature_capture(&sign, CaptureKind::Relative)
+        .unwrap();
+    assert_eq!(addr, 0xB + 0x10);
+
+    let addr: usize = p
+        .read_signature_capture(&sign, CaptureKind::Absolute)
+        .unwrap();
+    assert_eq!(addr, 0x10);
+
+    let sign = Signature::from_str("EB [??]").unwrap();
+    let addr: usize = p
+        .read_signature_capture(&sign, CaptureKind::Relative)
+        .unwrap();
+    assert_eq!(addr, 0xD - 0x4);
+
+    let res = p.read_signature_capture::<usize>(&sign, CaptureKind::Absolute);
+    assert!(matches!(res, Err(ProcessError::InvalidCaptureLength(1))));
+
+    let sign = Signature::from_str("EB FC").unwrap();
+    let res = p.read_signature_capture::<usize>(&sign, CaptureKind::Relative);
+    assert!(matches!(res, Err(ProcessError::SignatureNoCapture(_))));
+}
+
+#[cfg(feature = "database")]
+#[test]
+fn test_signature_set_resolve() {
+    use rosu_mem::database::SignatureSet;
+
+    let mut buff = vec![0u8; 0x40];
+    buff[0x0..0x6].copy_from_slice(&[0xAA, 0xBB, 0x20, 0x00, 0x00, 0x00]);
+    buff[0x10..0x12].copy_from_slice(&[0xCC, 0xDD]);
+    buff[0x20..0x24].copy_from_slice(&0x30u32.to_le_bytes());
+
+    let p = FakeProccess::new(buff);
+
+    let entries = r#"
+        [[signature]]
+        name = "ptr"
+        pattern = "AA BB [?? ?? ?? ??]"
+        capture = "absolute"
+
+        [[signature]]
+        name = "deref"
+        pattern = "AA BB [?? ?? ?? ??]"
+        capture = "absolute"
+        chain = [0]
+
+        [[signature]]
+        name = "alt"
+        pattern = "EE EE"
+
+        [[signature]]
+        name = "alt"
+        pattern = "CC DD"
+        offset = 2
+    "#;
+
+    let set = SignatureSet::from_toml(entries).unwrap();
+    let addresses = set.resolve(&p).unwrap();
+
+    assert_eq!(addresses.len(), 3);
+    assert_eq!(addresses["ptr"], 0x20);
+    assert_eq!(addresses["deref"], 0x30);
+    assert_eq!(addresses["alt"], 0x12);
+
+    let broken = r#"
+        [[signature]]
+        name = "missing"
+        pattern = "EE EF"
+
+        [[signature]]
+        name = "broken"
+        pattern = "CC DD"
+        chain = [0x100]
+    "#;
+
+    let set = SignatureSet::from_toml(&format!("{entries}{broken}")).unwrap();
+
+    let Err(DatabaseError::UnresolvedSignatures(failed)) = set.resolve(&p)
+    else {
+        panic!("expected unresolved signatures");
+    };
+
+    assert_eq!(failed.len(), 2);
+    assert_eq!(failed[0].name, "missing");
+    assert!(matches!(
+        failed[0].error,
+        ProcessError::SignatureNotFound(_)
+    ));
+    assert_eq!(failed[1].name, "broken");
+    assert!(matches!(
+        failed[1].error,
+        ProcessError::BrokenPointerChain { hop: 0, .. }
+    ));
+}
+
+#[cfg(feature = "database")]
+#[test]
+fn test_signature_cache() {
+    use rosu_mem::{cache::SignatureCache, database::SignatureSet};
+
+    let dir = std::env::temp_dir()
+        .join(format!("rosu-mem-cache-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let executable = dir.join("osu!.exe");
+    let cache_path = dir.join("signatures.cache");
+    std::fs::write(&executable, b"MZ build 1").unwrap();
+
+    let process = |matches: &[usize]| {
+        let mut buff = vec![0u8; 0x40];
+
+        for at in matches {
+            buff[*at..*at + 3].copy_from_slice(&[0xAA, 0xBB, 0xCC]);
+        }
+
+        let mut image = fake_region(0, 0x40);
+        image.kind = RegionKind::File;
+        image.path = Some(executable.clone());
+
+        FakeProccess::with_regions(buff, vec![image])
+    };
+
+    let set = SignatureSet::from_toml(
+        "[[signature]]\nname = \"value\"\npattern = \"AA BB CC\"",
+    )
+    .unwrap();
+
+    let mut cache = SignatureCache::open(&cache_path, &executable).unwrap();
+    assert!(cache.is_empty());
+
+    let addresses = set.resolve_cached(&process(&[0x30]), &mut cache).unwrap();
+    assert_eq!(addresses["value"], 0x30);
+    cache.save().unwrap();
+
+    // Cached match is used instead of the first one in memory
+    let mut cache = SignatureCache::open(&cache_path, &executable).unwrap();
+    assert_eq!(cache.len(), 1);
+
+    let p = process(&[0x10, 0x30]);
+    assert_eq!(set.resolve_cached(&p, &mut cache).unwrap()["value"], 0x30);
+    assert_eq!(set.resolve(&p).unwrap()["value"], 0x10);
+
+    // Cached match doesn't match memory anymore, scanning again
+    let p = process(&[0x10]);
+    assert_eq!(set.resolve_cached(&p, &mut cache).unwrap()["value"], 0x10);
+
+    // Different build of the executable
+    cache.save().unwrap();
+    std::fs::write(&executable, b"MZ build 2").unwrap();
+
+    let cache = SignatureCache::open(&cache_path, &executable).unwrap();
+    assert!(cache.is_empty());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
 prim_read_test!(i8);
 prim_read_test!(i16);
 prim_read_test!(i32);
@@ -398,6 +1690,21 @@ prim_read_test!(u128);
 prim_read_test!(f32);
 prim_read_test!(f64);
 
+prim_write_test!(i8);
+prim_write_test!(i16);
+prim_write_test!(i32);
+prim_write_test!(i64);
+prim_write_test!(i128);
+
+prim_write_test!(u8);
+prim_write_test!(u16);
+prim_write_test!(u32);
+prim_write_test!(u64);
+prim_write_test!(u128);
+
+prim_write_test!(f32);
+prim_write_test!(f64);
+
 prim_read_array_test!(i8);
 prim_read_array_test!(i16);
 prim_read_array_test!(i32);